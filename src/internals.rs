@@ -1,2 +1,6 @@
+pub mod awaitable;
 pub mod function_wrapper;
+pub mod scheduled_future;
+pub mod serializer;
+pub mod shared_function_queue;
 pub mod stream_controller;
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use std::{
+  future::Future,
+  pin::Pin,
+  sync::{Arc, Mutex},
+  task::{Context, Poll, Waker},
+};
+
+struct AwaitableState<Out> {
+  result: Option<Result<Out, RxError>>,
+  waker: Option<Waker>,
+}
+
+// Handed to the subscriber of an `Awaitable` to deliver its result.
+// Only the first result is kept.
+pub struct AwaitableSink<Out> {
+  state: Arc<Mutex<AwaitableState<Out>>>,
+}
+
+impl<Out> Clone for AwaitableSink<Out> {
+  fn clone(&self) -> Self {
+    AwaitableSink { state: Arc::clone(&self.state) }
+  }
+}
+
+impl<Out> AwaitableSink<Out> {
+  pub fn resolve(&self, result: Result<Out, RxError>) {
+    let waker = {
+      let mut state = self.state.lock().unwrap();
+      if state.result.is_some() {
+        return;
+      }
+      state.result = Some(result);
+      state.waker.take()
+    };
+    if let Some(w) = waker {
+      w.wake();
+    }
+  }
+}
+
+type AwaitableStart<'a, Out> =
+  Box<dyn FnOnce(AwaitableSink<Out>) -> Subscription<'a> + Send + 'a>;
+
+// A future that subscribes on its first poll and unsubscribes when it is
+// dropped.
+pub struct Awaitable<'a, Out> {
+  start: Option<AwaitableStart<'a, Out>>,
+  sink: AwaitableSink<Out>,
+  subscription: Option<Subscription<'a>>,
+}
+
+impl<'a, Out> Awaitable<'a, Out> {
+  pub fn new<F>(start: F) -> Awaitable<'a, Out>
+  where
+    F: FnOnce(AwaitableSink<Out>) -> Subscription<'a> + Send + 'a,
+  {
+    Awaitable {
+      start: Some(Box::new(start)),
+      sink: AwaitableSink {
+        state: Arc::new(Mutex::new(AwaitableState {
+          result: None,
+          waker: None,
+        })),
+      },
+      subscription: None,
+    }
+  }
+}
+
+impl<'a, Out> Future for Awaitable<'a, Out> {
+  type Output = Result<Out, RxError>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let this = self.get_mut();
+    if let Some(start) = this.start.take() {
+      this.subscription = Some(start(this.sink.clone()));
+    }
+
+    let mut state = this.sink.state.lock().unwrap();
+    if let Some(result) = state.result.take() {
+      Poll::Ready(result)
+    } else {
+      state.waker = Some(cx.waker().clone());
+      Poll::Pending
+    }
+  }
+}
+
+impl<'a, Out> Drop for Awaitable<'a, Out> {
+  fn drop(&mut self) {
+    if let Some(subscription) = self.subscription.take() {
+      subscription.unsubscribe();
+    }
+  }
+}
+
+pub(crate) fn no_elements_error() -> RxError {
+  RxError::from_error(std::io::Error::new(
+    std::io::ErrorKind::NotFound,
+    "sequence contains no elements",
+  ))
+}
@@ -44,6 +44,10 @@ where
   pub fn exists(&self) -> bool {
     !self.empty()
   }
+  // clones share the same id.
+  pub(crate) fn id(&self) -> usize {
+    Arc::as_ptr(&self.inner) as usize
+  }
 
   fn fetch_function(&self) -> Option<FunctionWrapperInner<In, Out>> {
     if let Some(x) = &*self.inner.read().unwrap() {
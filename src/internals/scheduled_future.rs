@@ -0,0 +1,93 @@
+use crate::prelude::*;
+use scheduler::IScheduler;
+use std::{
+  future::Future,
+  pin::Pin,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+  task::{Context, Poll, Wake, Waker},
+};
+
+type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+// Drives a future by polling it on a scheduler.
+// Every wake posts a poll to the scheduler; polls that arrive while another
+// one is running are folded into it, so a waker called from inside `poll`
+// (or from a scheduler that runs posts inline) never deadlocks.
+pub struct ScheduledFuture<Scheduler>
+where
+  Scheduler: IScheduler<'static> + Clone + Send + Sync + 'static,
+{
+  future: Mutex<Option<BoxedFuture>>,
+  notified: AtomicBool,
+  cancelled: AtomicBool,
+  scheduler: Scheduler,
+}
+
+impl<Scheduler> ScheduledFuture<Scheduler>
+where
+  Scheduler: IScheduler<'static> + Clone + Send + Sync + 'static,
+{
+  pub fn spawn<F>(future: F, scheduler: Scheduler) -> Arc<Self>
+  where
+    F: Future<Output = ()> + Send + 'static,
+  {
+    let task = Arc::new(ScheduledFuture {
+      future: Mutex::new(Some(Box::pin(future))),
+      notified: AtomicBool::new(false),
+      cancelled: AtomicBool::new(false),
+      scheduler,
+    });
+    Arc::clone(&task).wake();
+    task
+  }
+
+  // May be called from inside `poll`; the future is then dropped as soon as
+  // that poll returns.
+  pub fn cancel(&self) {
+    self.cancelled.store(true, Ordering::SeqCst);
+    if let Ok(mut future) = self.future.try_lock() {
+      future.take();
+    }
+    self.scheduler.abort();
+  }
+
+  fn run(self: &Arc<Self>) {
+    loop {
+      if !self.notified.load(Ordering::SeqCst) {
+        return;
+      }
+      let mut future = match self.future.try_lock() {
+        Ok(future) => future,
+        Err(_) => return,
+      };
+      if !self.notified.swap(false, Ordering::SeqCst) {
+        continue;
+      }
+      if let Some(f) = future.as_mut() {
+        let waker = Waker::from(Arc::clone(self));
+        let mut cx = Context::from_waker(&waker);
+        if let Poll::Ready(()) = f.as_mut().poll(&mut cx) {
+          future.take();
+        }
+      }
+      if self.cancelled.load(Ordering::SeqCst) {
+        future.take();
+        return;
+      }
+    }
+  }
+}
+
+impl<Scheduler> Wake for ScheduledFuture<Scheduler>
+where
+  Scheduler: IScheduler<'static> + Clone + Send + Sync + 'static,
+{
+  fn wake(self: Arc<Self>) {
+    self.notified.store(true, Ordering::SeqCst);
+    let task = Arc::clone(&self);
+    self.scheduler.post(move || task.run());
+  }
+}
@@ -0,0 +1,77 @@
+use crate::internals::function_wrapper::*;
+use crate::prelude::*;
+use std::{
+  collections::VecDeque,
+  sync::{Arc, Mutex},
+};
+
+struct SerializerState<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  queue: VecDeque<Material<Item>>,
+  emitting: bool,
+  terminated: bool,
+}
+
+// delivers notifications to `sink` one at a time. a caller that finds
+// another emission in progress only enqueues, and the emitting caller drains
+// the queue, so neither concurrent nor re-entrant calls overlap or block.
+#[derive(Clone)]
+pub struct Serializer<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  state: Arc<Mutex<SerializerState<Item>>>,
+  sink: FunctionWrapper<'a, Material<Item>, ()>,
+}
+
+impl<'a, Item> Serializer<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn new<F>(sink: F) -> Serializer<'a, Item>
+  where
+    F: Fn(Material<Item>) + Send + Sync + 'a,
+  {
+    Serializer {
+      state: Arc::new(Mutex::new(SerializerState {
+        queue: VecDeque::new(),
+        emitting: false,
+        terminated: false,
+      })),
+      sink: FunctionWrapper::new(sink),
+    }
+  }
+
+  pub fn push(&self, m: Material<Item>) {
+    {
+      let mut state = self.state.lock().unwrap();
+      if state.terminated {
+        return;
+      }
+      if !matches!(m, Material::Next(_)) {
+        state.terminated = true;
+      }
+      state.queue.push_back(m);
+      if state.emitting {
+        return;
+      }
+      state.emitting = true;
+    }
+
+    loop {
+      let m = {
+        let mut state = self.state.lock().unwrap();
+        match state.queue.pop_front() {
+          Some(m) => m,
+          None => {
+            state.emitting = false;
+            return;
+          }
+        }
+      };
+      self.sink.call(m);
+    }
+  }
+}
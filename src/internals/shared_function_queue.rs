@@ -0,0 +1,100 @@
+use crate::prelude::*;
+use std::{
+  collections::HashSet,
+  sync::{Arc, RwLock},
+  time::{Duration, Instant},
+};
+
+// A view of an `AsyncFunctionQueue` that is shared with others.
+// `abort` cancels only the work posted through this view and keeps the
+// queue itself running.
+#[derive(Clone)]
+pub struct SharedFunctionQueue<'a> {
+  queue: schedulers::AsyncFunctionQueue<'a>,
+  timers: Arc<RwLock<HashSet<(Instant, u64)>>>,
+  aborted: Arc<RwLock<bool>>,
+}
+
+impl<'a> SharedFunctionQueue<'a> {
+  pub fn new(
+    queue: schedulers::AsyncFunctionQueue<'a>,
+  ) -> SharedFunctionQueue<'a> {
+    SharedFunctionQueue {
+      queue,
+      timers: Arc::new(RwLock::new(HashSet::new())),
+      aborted: Arc::new(RwLock::new(false)),
+    }
+  }
+
+  pub fn is_aborted(&self) -> bool {
+    *self.aborted.read().unwrap()
+  }
+
+  pub fn pending_timers(&self) -> usize {
+    self.timers.read().unwrap().len()
+  }
+
+  pub fn post<F>(&self, f: F)
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    let aborted = Arc::clone(&self.aborted);
+    self.queue.post(move || {
+      if !*aborted.read().unwrap() {
+        f();
+      }
+    });
+  }
+
+  pub fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'a>
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    if self.is_aborted() {
+      return Subscription::new(|| {}, || false);
+    }
+
+    let key = {
+      // keep `slot` locked until the key is registered. a timer firing right
+      // away waits for it, so it always finds and removes its own key.
+      let slot = Arc::new(RwLock::new(None::<(Instant, u64)>));
+      let mut slot_guard = slot.write().unwrap();
+      let key = {
+        let slot = Arc::clone(&slot);
+        let timers = Arc::clone(&self.timers);
+        let aborted = Arc::clone(&self.aborted);
+        self.queue.post_at(Instant::now() + dur, move || {
+          let key = *slot.read().unwrap();
+          if let Some(key) = key {
+            timers.write().unwrap().remove(&key);
+          }
+          if !*aborted.read().unwrap() {
+            f();
+          }
+        })
+      };
+      self.timers.write().unwrap().insert(key);
+      *slot_guard = Some(key);
+      key
+    };
+
+    let queue_unsub = self.queue.clone();
+    let queue_issub = self.queue.clone();
+    let timers = Arc::clone(&self.timers);
+    Subscription::new(
+      move || {
+        let mut timers = timers.write().unwrap();
+        queue_unsub.cancel(&key);
+        timers.remove(&key);
+      },
+      move || queue_issub.is_pending(&key),
+    )
+  }
+
+  pub fn abort(&self) {
+    *self.aborted.write().unwrap() = true;
+    let mut timers = self.timers.write().unwrap();
+    timers.iter().for_each(|key| self.queue.cancel(key));
+    timers.clear();
+  }
+}
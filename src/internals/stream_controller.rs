@@ -55,6 +55,23 @@ where
     Next: Fn(i32, XItem) + Send + Sync + 'a,
     Error: Fn(i32, RxError) + Send + Sync + 'a,
     Complete: Fn(i32) -> () + Send + Sync + 'a,
+  {
+    self.new_observer_with_serial(next, error, complete).1
+  }
+
+  // same as `new_observer`, but also returns the serial of the observer so
+  // that it can be aborted later with `upstream_abort_observe`.
+  pub fn new_observer_with_serial<XItem, Next, Error, Complete>(
+    &self,
+    next: Next,
+    error: Error,
+    complete: Complete,
+  ) -> (i32, Observer<'a, XItem>)
+  where
+    XItem: Clone + Send + Sync + 'a,
+    Next: Fn(i32, XItem) + Send + Sync + 'a,
+    Error: Fn(i32, RxError) + Send + Sync + 'a,
+    Complete: Fn(i32) + Send + Sync + 'a,
   {
     let serial = {
       let mut x = self.serial.write().unwrap();
@@ -78,7 +95,7 @@ where
       serial.clone(),
       FunctionWrapper::new(move |_| o_unsub.unsubscribe()),
     );
-    observer
+    (serial, observer)
   }
 
   pub fn sink_next(&self, x: Item) {
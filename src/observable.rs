@@ -20,6 +20,11 @@ where
     Observable { source: FunctionWrapper::new(source) }
   }
 
+  // clones of an observable share the same id.
+  pub(crate) fn id(&self) -> usize {
+    self.source.id()
+  }
+
   pub(crate) fn inner_subscribe(
     &self,
     observer: Observer<'a, Item>,
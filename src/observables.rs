@@ -1,6 +1,8 @@
+pub mod combine_latest_tuple;
 pub mod defer;
 pub mod empty;
 pub mod error;
+pub mod from_future;
 pub mod from_iter;
 pub mod from_result;
 pub mod just;
@@ -8,16 +10,23 @@ pub mod never;
 pub mod range;
 pub mod repeat;
 pub mod start;
+pub mod when;
+pub mod zip_tuple;
 
 #[cfg(not(feature = "web"))]
 pub mod interval;
 #[cfg(not(feature = "web"))]
 pub mod timer;
 
+#[cfg(feature = "stream")]
+pub mod from_stream;
+
 pub mod observables {
+  pub use crate::observables::combine_latest_tuple::*;
   pub use crate::observables::defer::*;
   pub use crate::observables::empty::*;
   pub use crate::observables::error::*;
+  pub use crate::observables::from_future::*;
   pub use crate::observables::from_iter::*;
   pub use crate::observables::from_result::*;
   pub use crate::observables::just::*;
@@ -25,9 +34,14 @@ pub mod observables {
   pub use crate::observables::range::*;
   pub use crate::observables::repeat::*;
   pub use crate::observables::start::*;
+  pub use crate::observables::when::*;
+  pub use crate::observables::zip_tuple::*;
 
   #[cfg(not(feature = "web"))]
   pub use crate::observables::interval::*;
   #[cfg(not(feature = "web"))]
   pub use crate::observables::timer::*;
+
+  #[cfg(feature = "stream")]
+  pub use crate::observables::from_stream::*;
 }
@@ -0,0 +1,148 @@
+use crate::internals::stream_controller::*;
+use crate::prelude::*;
+use std::sync::{Arc, RwLock};
+
+macro_rules! combine_latest_tuple {
+  ($name: ident; $(($t: ident, $o: ident, $idx: tt)),+) => {
+    #[allow(clippy::too_many_arguments)]
+    pub fn $name<'a, $($t),+>(
+      $($o: Observable<'a, $t>),+
+    ) -> Observable<'a, ($($t),+)>
+    where
+      $($t: Clone + Send + Sync + 'a),+
+    {
+      Observable::create(move |s| {
+        let sctl = StreamController::new(s);
+        let latest = Arc::new(RwLock::new(($(None::<$t>),+)));
+
+        // nothing is emitted until every source has a value.
+        let snapshot = |l: &($(Option<$t>),+)| {
+          if $(l.$idx.is_some())&&+ {
+            Some(($(l.$idx.clone().unwrap()),+))
+          } else {
+            None
+          }
+        };
+
+        $({
+          let latest_next = Arc::clone(&latest);
+          let latest_complete = Arc::clone(&latest);
+          let sctl_next = sctl.clone();
+          let sctl_error = sctl.clone();
+          let sctl_complete = sctl.clone();
+          $o.inner_subscribe(sctl.new_observer(
+            move |_, x| {
+              let items = {
+                let mut l = latest_next.write().unwrap();
+                l.$idx = Some(x);
+                snapshot(&l)
+              };
+              if let Some(items) = items {
+                sctl_next.sink_next(items);
+              }
+            },
+            move |_, e| {
+              sctl_error.sink_error(e);
+            },
+            move |serial| {
+              // a source that completes without a value means nothing
+              // can ever be emitted.
+              if latest_complete.read().unwrap().$idx.is_none() {
+                sctl_complete.sink_complete_force();
+              } else {
+                sctl_complete.sink_complete(&serial);
+              }
+            },
+          ));
+        })+
+      })
+    }
+  };
+}
+
+combine_latest_tuple!(combine_latest2; (A, a, 0), (B, b, 1));
+combine_latest_tuple!(combine_latest3; (A, a, 0), (B, b, 1), (C, c, 2));
+combine_latest_tuple!(
+  combine_latest4;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3)
+);
+combine_latest_tuple!(
+  combine_latest5;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4)
+);
+combine_latest_tuple!(
+  combine_latest6;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4), (F, f, 5)
+);
+combine_latest_tuple!(
+  combine_latest7;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4), (F, f, 5),
+  (G, g, 6)
+);
+combine_latest_tuple!(
+  combine_latest8;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4), (F, f, 5),
+  (G, g, 6), (H, h, 7)
+);
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let a = subjects::Subject::<i32>::new();
+    let b = subjects::Subject::<String>::new();
+    let c = subjects::Subject::<bool>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      observables::combine_latest3(
+        a.observable(),
+        b.observable(),
+        c.observable(),
+      )
+      .subscribe(
+        move |x| log.write().unwrap().push(x),
+        print_error!(),
+        print_complete!(),
+      );
+    }
+
+    a.next(1);
+    b.next("x".to_owned());
+    a.next(2);
+    c.next(true);
+    b.next("y".to_owned());
+    a.complete();
+    c.next(false);
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![
+        (2, "x".to_owned(), true),
+        (2, "y".to_owned(), true),
+        (2, "y".to_owned(), false)
+      ]
+    );
+  }
+
+  #[test]
+  fn never_filled() {
+    let completed = Arc::new(RwLock::new(false));
+    {
+      let completed = Arc::clone(&completed);
+      observables::combine_latest2(
+        observables::empty::<i32>(),
+        observables::just("a"),
+      )
+      .subscribe(
+        print_next_fmt!("{:?}"),
+        print_error!(),
+        move || *completed.write().unwrap() = true,
+      );
+    }
+    assert!(*completed.read().unwrap());
+  }
+}
@@ -0,0 +1,179 @@
+use crate::internals::{scheduled_future::*, stream_controller::*};
+use crate::prelude::*;
+use scheduler::IScheduler;
+use std::{
+  future::Future,
+  sync::{Arc, Mutex},
+};
+
+// The future is consumed by the first subscription. Wrap the call in
+// `observables::defer` to get a fresh future for every subscription.
+pub fn from_future<Item, E, Fut, Scheduler, SchedulerCreator>(
+  fut: Fut,
+  scheduler_ctor: SchedulerCreator,
+) -> Observable<'static, Item>
+where
+  Item: Clone + Send + Sync + 'static,
+  E: std::fmt::Debug + Send + Sync + 'static,
+  Fut: Future<Output = Result<Item, E>> + Send + 'static,
+  Scheduler: IScheduler<'static> + Clone + Send + Sync + 'static,
+  SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'static,
+{
+  let fut = Arc::new(Mutex::new(Some(fut)));
+  Observable::create(move |s| {
+    let sctl = StreamController::new(s);
+    let fut = match fut.lock().unwrap().take() {
+      Some(fut) => fut,
+      None => {
+        sctl.sink_error(RxError::from_error(
+          "from_future: the future has already been consumed",
+        ));
+        return;
+      }
+    };
+
+    let sctl_task = sctl.clone();
+    let task = ScheduledFuture::spawn(
+      async move {
+        match fut.await {
+          Ok(x) => {
+            sctl_task.sink_next(x);
+            sctl_task.sink_complete_force();
+          }
+          Err(e) => sctl_task.sink_error(RxError::from_error(e)),
+        }
+      },
+      scheduler_ctor(),
+    );
+    sctl.set_on_finalize(move || task.cancel());
+  })
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    future::{self, Future},
+    sync::{Arc, Mutex, RwLock},
+    task::Poll,
+    thread, time,
+  };
+
+  fn delayed<T>(dur: time::Duration, x: T) -> impl Future<Output = T>
+  where
+    T: Send + 'static,
+  {
+    let state = Arc::new(Mutex::new((
+      None,
+      None::<std::task::Waker>,
+    )));
+    {
+      let state = Arc::clone(&state);
+      thread::spawn(move || {
+        thread::sleep(dur);
+        let mut state = state.lock().unwrap();
+        state.0 = Some(x);
+        if let Some(w) = state.1.take() {
+          w.wake();
+        }
+      });
+    }
+    future::poll_fn(move |cx| {
+      let mut state = state.lock().unwrap();
+      if let Some(x) = state.0.take() {
+        Poll::Ready(x)
+      } else {
+        state.1 = Some(cx.waker().clone());
+        Poll::Pending
+      }
+    })
+  }
+
+  #[test]
+  fn basic() {
+    observables::from_future(
+      async { Ok::<_, ()>(1) },
+      schedulers::default_scheduler(),
+    )
+    .subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+
+    observables::from_future(
+      async { Err::<i32, _>("ERR!") },
+      schedulers::default_scheduler(),
+    )
+    .subscribe(
+      print_next_fmt!("{}"),
+      print_error_as!(&str),
+      print_complete!(),
+    );
+  }
+
+  #[test]
+  fn thread() {
+    let result = Arc::new(RwLock::new(None));
+    {
+      let result = Arc::clone(&result);
+      observables::from_future(
+        async {
+          let x = delayed(time::Duration::from_millis(100), 10).await;
+          Ok::<_, ()>(x * 2)
+        },
+        schedulers::new_thread_scheduler(),
+      )
+      .subscribe(
+        move |x| *result.write().unwrap() = Some(x),
+        junk_error!(),
+        junk_complete!(),
+      );
+    }
+    thread::sleep(time::Duration::from_millis(300));
+    assert_eq!(*result.read().unwrap(), Some(20));
+  }
+
+  #[test]
+  fn cancel() {
+    let done = Arc::new(RwLock::new(false));
+    let sbsc = {
+      let done = Arc::clone(&done);
+      observables::from_future(
+        async move {
+          delayed(time::Duration::from_millis(200), ()).await;
+          *done.write().unwrap() = true;
+          Ok::<_, ()>(1)
+        },
+        schedulers::new_thread_scheduler(),
+      )
+      .subscribe(
+        print_next_fmt!("{}"),
+        print_error!(),
+        print_complete!(),
+      )
+    };
+    thread::sleep(time::Duration::from_millis(50));
+    sbsc.unsubscribe();
+    thread::sleep(time::Duration::from_millis(300));
+    assert!(!*done.read().unwrap());
+  }
+
+  #[test]
+  fn consumed() {
+    let o = observables::from_future(
+      async { Ok::<_, ()>(1) },
+      schedulers::default_scheduler(),
+    );
+    o.subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+    o.subscribe(
+      print_next_fmt!("{}"),
+      print_error_as!(&str),
+      print_complete!(),
+    );
+  }
+}
@@ -0,0 +1,155 @@
+use crate::internals::{scheduled_future::*, stream_controller::*};
+use crate::prelude::*;
+use futures_core::Stream;
+use scheduler::IScheduler;
+use std::{
+  future,
+  sync::{Arc, Mutex},
+};
+
+// The stream is consumed by the first subscription. Wrap the call in
+// `observables::defer` to get a fresh stream for every subscription.
+pub fn from_stream<Item, E, St, Scheduler, SchedulerCreator>(
+  stream: St,
+  scheduler_ctor: SchedulerCreator,
+) -> Observable<'static, Item>
+where
+  Item: Clone + Send + Sync + 'static,
+  E: std::fmt::Debug + Send + Sync + 'static,
+  St: Stream<Item = Result<Item, E>> + Send + 'static,
+  Scheduler: IScheduler<'static> + Clone + Send + Sync + 'static,
+  SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'static,
+{
+  let stream = Arc::new(Mutex::new(Some(stream)));
+  Observable::create(move |s| {
+    let sctl = StreamController::new(s);
+    let stream = match stream.lock().unwrap().take() {
+      Some(stream) => stream,
+      None => {
+        sctl.sink_error(RxError::from_error(
+          "from_stream: the stream has already been consumed",
+        ));
+        return;
+      }
+    };
+
+    let sctl_task = sctl.clone();
+    let task = ScheduledFuture::spawn(
+      async move {
+        let mut stream = Box::pin(stream);
+        while sctl_task.is_subscribed() {
+          match future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            Some(Ok(x)) => sctl_task.sink_next(x),
+            Some(Err(e)) => {
+              sctl_task.sink_error(RxError::from_error(e));
+              break;
+            }
+            None => {
+              sctl_task.sink_complete_force();
+              break;
+            }
+          }
+        }
+      },
+      scheduler_ctor(),
+    );
+    sctl.set_on_finalize(move || task.cancel());
+  })
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use futures_core::Stream;
+  use std::{
+    pin::Pin,
+    sync::{Arc, RwLock},
+    task::{Context, Poll},
+    thread, time,
+  };
+
+  struct Counter {
+    n: i32,
+    max: i32,
+  }
+
+  impl Stream for Counter {
+    type Item = Result<i32, &'static str>;
+    fn poll_next(
+      self: Pin<&mut Self>,
+      cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+      let this = self.get_mut();
+      if this.n == this.max {
+        return Poll::Ready(None);
+      }
+      this.n += 1;
+      if this.n % 2 == 0 {
+        // yield to the scheduler every other item.
+        cx.waker().wake_by_ref();
+        Poll::Pending
+      } else if this.n < 0 {
+        Poll::Ready(Some(Err("ERR!")))
+      } else {
+        Poll::Ready(Some(Ok(this.n)))
+      }
+    }
+  }
+
+  #[test]
+  fn basic() {
+    let items = Arc::new(RwLock::new(Vec::new()));
+    {
+      let items = Arc::clone(&items);
+      observables::from_stream(
+        Counter { n: 0, max: 10 },
+        schedulers::default_scheduler(),
+      )
+      .subscribe(
+        move |x| items.write().unwrap().push(x),
+        print_error!(),
+        print_complete!(),
+      );
+    }
+    assert_eq!(
+      *items.read().unwrap(),
+      vec![1, 3, 5, 7, 9]
+    );
+  }
+
+  #[test]
+  fn error() {
+    observables::from_stream(
+      Counter { n: -4, max: 10 },
+      schedulers::default_scheduler(),
+    )
+    .subscribe(
+      print_next_fmt!("{}"),
+      print_error_as!(&str),
+      print_complete!(),
+    );
+  }
+
+  #[test]
+  fn cancel() {
+    let items = Arc::new(RwLock::new(Vec::new()));
+    let sbsc = {
+      let items = Arc::clone(&items);
+      observables::from_stream(
+        Counter { n: 0, max: i32::MAX },
+        schedulers::new_thread_scheduler(),
+      )
+      .subscribe(
+        move |x| items.write().unwrap().push(x),
+        print_error!(),
+        print_complete!(),
+      )
+    };
+    thread::sleep(time::Duration::from_millis(50));
+    sbsc.unsubscribe();
+    thread::sleep(time::Duration::from_millis(20));
+    let n = items.read().unwrap().len();
+    thread::sleep(time::Duration::from_millis(100));
+    assert_eq!(items.read().unwrap().len(), n);
+  }
+}
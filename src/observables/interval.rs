@@ -1,30 +1,39 @@
+use crate::internals::stream_controller::*;
 use crate::prelude::*;
-use scheduler::IScheduler;
-use std::{thread, time::Duration};
+use scheduler::ITimedScheduler;
+use std::{
+  sync::{Arc, RwLock},
+  time::Duration,
+};
 
 pub fn interval<'a, Scheduler, SchedulerCreator>(
   dur: Duration,
   scheduler_ctor: SchedulerCreator,
 ) -> Observable<'a, u64>
 where
-  Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
   SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
 {
   Observable::create(move |s| {
     let scheduler = scheduler_ctor();
-    let scheduler_in_post = scheduler.clone();
-    scheduler.post(move || {
-      let mut n = 0;
-      loop {
-        thread::sleep(dur);
-        if !s.is_subscribed() {
-          break;
-        }
-        s.next(n);
-        n += 1;
-      }
-      scheduler_in_post.abort();
-    })
+    let sctl = StreamController::new(s);
+    {
+      let scheduler = scheduler.clone();
+      sctl.set_on_finalize(move || {
+        scheduler.abort();
+      });
+    }
+
+    let n = Arc::new(RwLock::new(0));
+    scheduler.schedule_periodic(dur, move || {
+      let x = {
+        let mut n = n.write().unwrap();
+        let x = *n;
+        *n += 1;
+        x
+      };
+      sctl.sink_next(x);
+    });
   })
 }
 
@@ -1,24 +1,30 @@
+use crate::internals::stream_controller::*;
 use crate::prelude::*;
-use scheduler::IScheduler;
-use std::{thread, time::Duration};
+use scheduler::ITimedScheduler;
+use std::time::Duration;
 
 pub fn timer<'a, Scheduler, SchedulerCreator>(
   dur: Duration,
   scheduler_ctor: SchedulerCreator,
 ) -> Observable<'a, ()>
 where
-  Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
   SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
 {
   Observable::create(move |s| {
     let scheduler = scheduler_ctor();
-    let scheduler_in_post = scheduler.clone();
-    scheduler.post(move || {
-      thread::sleep(dur);
-      s.next(());
-      s.complete();
-      scheduler_in_post.abort();
-    })
+    let sctl = StreamController::new(s);
+    {
+      let scheduler = scheduler.clone();
+      sctl.set_on_finalize(move || {
+        scheduler.abort();
+      });
+    }
+
+    scheduler.schedule_after(dur, move || {
+      sctl.sink_next(());
+      sctl.sink_complete_force();
+    });
   })
 }
 
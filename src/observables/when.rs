@@ -0,0 +1,211 @@
+use crate::internals::stream_controller::*;
+use crate::operators::pattern::AnyItem;
+use crate::prelude::*;
+use std::{
+  collections::VecDeque,
+  sync::{Arc, RwLock},
+};
+
+struct WhenState {
+  queues: Vec<VecDeque<AnyItem>>,
+  completed: Vec<bool>,
+  // indices into `queues` for each plan.
+  plans: Vec<Vec<usize>>,
+  active: Vec<bool>,
+}
+
+impl WhenState {
+  // pops one item of every source of the first plan that can fire.
+  fn take_matched(&mut self) -> Option<(usize, Vec<AnyItem>)> {
+    let idx = (0..self.plans.len()).find(|&i| {
+      self.active[i]
+        && self.plans[i].iter().all(|&q| !self.queues[q].is_empty())
+    })?;
+    let items = self.plans[idx]
+      .iter()
+      .map(|&q| self.queues[q].pop_front().unwrap())
+      .collect();
+    Some((idx, items))
+  }
+
+  // a plan can no longer fire once one of its sources has completed with
+  // nothing left in its queue.
+  fn deactivate_finished(&mut self) -> bool {
+    for i in 0..self.plans.len() {
+      if self.plans[i]
+        .iter()
+        .any(|&q| self.completed[q] && self.queues[q].is_empty())
+      {
+        self.active[i] = false;
+      }
+    }
+    self.active.iter().all(|x| !x)
+  }
+}
+
+pub fn when<'a, Out>(plans: &[operators::Plan<'a, Out>]) -> Observable<'a, Out>
+where
+  Out: Clone + Send + Sync + 'a,
+{
+  // an observable used by several plans is subscribed only once.
+  let mut sources = Vec::<(usize, Observable<'a, AnyItem>)>::new();
+  let plan_sources = plans
+    .iter()
+    .map(|plan| {
+      plan
+        .sources
+        .iter()
+        .map(
+          |(id, o)| match sources.iter().position(|(x, _)| x == id) {
+            Some(pos) => pos,
+            None => {
+              sources.push((*id, o.clone()));
+              sources.len() - 1
+            }
+          },
+        )
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+  let selectors = plans
+    .iter()
+    .map(|plan| plan.selector_f.clone())
+    .collect::<Vec<_>>();
+
+  Observable::create(move |s| {
+    let state = Arc::new(RwLock::new(WhenState {
+      queues: vec![VecDeque::new(); sources.len()],
+      completed: vec![false; sources.len()],
+      plans: plan_sources.clone(),
+      active: vec![true; plan_sources.len()],
+    }));
+    let sctl = StreamController::new(s);
+
+    if plan_sources.is_empty() {
+      sctl.sink_complete_force();
+      return;
+    }
+
+    let drain = {
+      let state = Arc::clone(&state);
+      let selectors = selectors.clone();
+      let sctl = sctl.clone();
+      move || {
+        loop {
+          let matched = state.write().unwrap().take_matched();
+          match matched {
+            Some((idx, items)) => {
+              sctl.sink_next(selectors[idx].call(items));
+            }
+            None => break,
+          }
+        }
+        if state.write().unwrap().deactivate_finished() {
+          sctl.sink_complete_force();
+        }
+      }
+    };
+
+    sources.iter().enumerate().for_each(|(idx, (_, o))| {
+      let state_next = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+      let drain_next = drain.clone();
+      let drain_complete = drain.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+      o.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          state_next.write().unwrap().queues[idx].push_back(x);
+          drain_next();
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          sctl_complete.upstream_abort_observe(&serial);
+          state_complete.write().unwrap().completed[idx] = true;
+          drain_complete();
+        },
+      ));
+    });
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      let a = observables::from_iter(0..3);
+      let b = observables::from_iter(vec!["a", "b", "c", "d"].into_iter());
+      let c = observables::from_iter(vec![0.5, 1.5].into_iter());
+      observables::when(&[a
+        .and(&b)
+        .and(&c)
+        .then(|(x, y, z)| format!("{}{}{}", x, y, z))])
+      .subscribe(
+        move |x| log.write().unwrap().push(x),
+        print_error!(),
+        print_complete!(),
+      );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec!["0a0.5".to_owned(), "1b1.5".to_owned()]
+    );
+  }
+
+  #[test]
+  fn shared_source() {
+    let a = subjects::Subject::<i32>::new();
+    let b = subjects::Subject::<i32>::new();
+    let c = subjects::Subject::<&str>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let completed = Arc::new(RwLock::new(false));
+
+    {
+      let log = Arc::clone(&log);
+      let completed = Arc::clone(&completed);
+      let oa = a.observable();
+      observables::when(&[
+        oa.and(&b.observable())
+          .then(|(x, y)| format!("ab {} {}", x, y)),
+        oa.and(&c.observable())
+          .then(|(x, y)| format!("ac {} {}", x, y)),
+      ])
+      .subscribe(
+        move |x| log.write().unwrap().push(x),
+        print_error!(),
+        move || *completed.write().unwrap() = true,
+      );
+    }
+
+    a.next(1);
+    c.next("x");
+    b.next(10);
+    // 1 was consumed by the second plan.
+    a.next(2);
+    b.complete();
+    assert!(!*completed.read().unwrap());
+    c.complete();
+    assert!(*completed.read().unwrap());
+    assert_eq!(
+      *log.read().unwrap(),
+      vec!["ac 1 x".to_owned(), "ab 2 10".to_owned()]
+    );
+  }
+
+  #[test]
+  fn single() {
+    observables::when(&[observables::range(0, 3).then(|x| x * 10)]).subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+  }
+}
@@ -0,0 +1,147 @@
+use crate::internals::stream_controller::*;
+use crate::prelude::*;
+use std::{
+  collections::VecDeque,
+  sync::{Arc, RwLock},
+};
+
+macro_rules! zip_tuple {
+  ($name: ident; $len: expr; $(($t: ident, $o: ident, $idx: tt)),+) => {
+    #[allow(clippy::too_many_arguments)]
+    pub fn $name<'a, $($t),+>(
+      $($o: Observable<'a, $t>),+
+    ) -> Observable<'a, ($($t),+)>
+    where
+      $($t: Clone + Send + Sync + 'a),+
+    {
+      Observable::create(move |s| {
+        let sctl = StreamController::new(s);
+        let queues = Arc::new(RwLock::new(($(VecDeque::<$t>::new()),+)));
+        let completed = Arc::new(RwLock::new([false; $len]));
+
+        // emits while every queue has an item, and completes once a
+        // completed source has nothing left to pair.
+        let drain = {
+          let queues = Arc::clone(&queues);
+          let completed = Arc::clone(&completed);
+          let sctl = sctl.clone();
+          move || loop {
+            let items = {
+              let mut q = queues.write().unwrap();
+              if $(q.$idx.is_empty())||+ {
+                let c = completed.read().unwrap();
+                if $((c[$idx] && q.$idx.is_empty()))||+ {
+                  drop(q);
+                  drop(c);
+                  sctl.sink_complete_force();
+                }
+                break;
+              }
+              ($(q.$idx.pop_front().unwrap()),+)
+            };
+            sctl.sink_next(items);
+          }
+        };
+
+        $({
+          let queues = Arc::clone(&queues);
+          let completed = Arc::clone(&completed);
+          let drain_next = drain.clone();
+          let drain_complete = drain.clone();
+          let sctl_error = sctl.clone();
+          $o.inner_subscribe(sctl.new_observer(
+            move |_, x| {
+              queues.write().unwrap().$idx.push_back(x);
+              drain_next();
+            },
+            move |_, e| {
+              sctl_error.sink_error(e);
+            },
+            move |_| {
+              completed.write().unwrap()[$idx] = true;
+              drain_complete();
+            },
+          ));
+        })+
+      })
+    }
+  };
+}
+
+zip_tuple!(zip2; 2; (A, a, 0), (B, b, 1));
+zip_tuple!(zip3; 3; (A, a, 0), (B, b, 1), (C, c, 2));
+zip_tuple!(zip4; 4; (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3));
+zip_tuple!(zip5; 5; (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4));
+zip_tuple!(
+  zip6; 6;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4), (F, f, 5)
+);
+zip_tuple!(
+  zip7; 7;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4), (F, f, 5),
+  (G, g, 6)
+);
+zip_tuple!(
+  zip8; 8;
+  (A, a, 0), (B, b, 1), (C, c, 2), (D, d, 3), (E, e, 4), (F, f, 5),
+  (G, g, 6), (H, h, 7)
+);
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::zip3(
+        observables::from_iter(0..3),
+        observables::from_iter(vec!["a", "b", "c", "d"].into_iter()),
+        observables::just(1.5).repeat(0),
+      )
+      .take(10)
+      .subscribe(
+        move |x| log.write().unwrap().push(x),
+        print_error!(),
+        print_complete!(),
+      );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![(0, "a", 1.5), (1, "b", 1.5), (2, "c", 1.5)]
+    );
+  }
+
+  #[test]
+  fn subject() {
+    let a = subjects::Subject::<i32>::new();
+    let b = subjects::Subject::<String>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let completed = Arc::new(RwLock::new(false));
+
+    {
+      let log = Arc::clone(&log);
+      let completed = Arc::clone(&completed);
+      observables::zip2(a.observable(), b.observable()).subscribe(
+        move |x| log.write().unwrap().push(x),
+        print_error!(),
+        move || *completed.write().unwrap() = true,
+      );
+    }
+
+    a.next(1);
+    a.next(2);
+    b.next("x".to_owned());
+    a.complete();
+    assert!(!*completed.read().unwrap());
+    b.next("y".to_owned());
+    assert!(*completed.read().unwrap());
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![(1, "x".to_owned()), (2, "y".to_owned())]
+    );
+  }
+}
@@ -1,20 +1,34 @@
 pub mod all;
 pub mod amb;
+pub mod buffer;
+pub mod buffer_toggle;
 pub mod buffer_with_count;
 pub mod combine_latest;
 pub mod concat;
+pub mod concat_map;
 pub mod contains;
 pub mod count;
+pub mod count_async;
+pub mod debounce_with;
 pub mod default_if_empty;
 pub mod dematerialize;
+pub mod distinct;
 pub mod distinct_until_changed;
+pub mod distinct_until_changed_with;
 pub mod element_at;
+pub mod exhaust_map;
 pub mod filter;
 pub mod first;
+pub mod first_async;
 pub mod flat_map;
+pub mod flat_map_with_concurrency;
+pub mod for_each_async;
 pub mod group_by;
+pub mod group_join;
 pub mod ignore_elements;
+pub mod join;
 pub mod last;
+pub mod last_async;
 pub mod map;
 pub mod map_to_any;
 pub mod materialize;
@@ -22,16 +36,22 @@ pub mod max;
 pub mod merge;
 pub mod min;
 pub mod observe_on;
+pub mod observe_on_bounded;
 pub mod on_error_resume_next;
+pub mod pattern;
 pub mod publish;
 pub mod reduce;
 pub mod ref_count;
+// private so the module doesn't clash with `observables::repeat` in the prelude.
+mod repeat;
 pub mod replay;
 pub mod retry;
 pub mod retry_when;
 pub mod sample;
 pub mod scan;
 pub mod sequence_equal;
+pub mod serialize;
+pub mod single_async;
 pub mod skip;
 pub mod skip_last;
 pub mod skip_until;
@@ -40,45 +60,79 @@ pub mod start_with;
 pub mod subscribe_on;
 pub mod sum;
 pub mod sum_and_count;
+pub mod switch_map;
 pub mod switch_on_next;
 pub mod take;
 pub mod take_last;
 pub mod take_until;
 pub mod take_while;
 pub mod tap;
+pub mod to_blocking_iter;
 pub mod to_vec;
+pub mod window;
+pub mod window_toggle;
 pub mod window_with_count;
+pub mod with_latest_from;
 pub mod zip;
 
+#[cfg(not(feature = "web"))]
+pub mod buffer_with_time;
+#[cfg(not(feature = "web"))]
+pub mod buffer_with_time_or_count;
 #[cfg(not(feature = "web"))]
 pub mod debounce;
 #[cfg(not(feature = "web"))]
 pub mod delay;
 #[cfg(not(feature = "web"))]
+pub mod sample_time;
+#[cfg(not(feature = "web"))]
+pub mod throttle;
+#[cfg(not(feature = "web"))]
 pub mod time_interval;
 #[cfg(not(feature = "web"))]
 pub mod timeout;
 #[cfg(not(feature = "web"))]
 pub mod timestamp;
+#[cfg(not(feature = "web"))]
+pub mod window_with_time;
+#[cfg(not(feature = "web"))]
+pub mod window_with_time_or_count;
+
+#[cfg(feature = "stream")]
+pub mod into_stream;
 
 pub mod operators {
   pub use crate::operators::all::*;
   pub use crate::operators::amb::*;
+  pub use crate::operators::buffer::*;
+  pub use crate::operators::buffer_toggle::*;
   pub use crate::operators::buffer_with_count::*;
   pub use crate::operators::combine_latest::*;
   pub use crate::operators::concat::*;
+  pub use crate::operators::concat_map::*;
   pub use crate::operators::contains::*;
   pub use crate::operators::count::*;
+  pub use crate::operators::count_async::*;
+  pub use crate::operators::debounce_with::*;
   pub use crate::operators::default_if_empty::*;
   pub use crate::operators::dematerialize::*;
+  pub use crate::operators::distinct::*;
   pub use crate::operators::distinct_until_changed::*;
+  pub use crate::operators::distinct_until_changed_with::*;
   pub use crate::operators::element_at::*;
+  pub use crate::operators::exhaust_map::*;
   pub use crate::operators::filter::*;
   pub use crate::operators::first::*;
+  pub use crate::operators::first_async::*;
   pub use crate::operators::flat_map::*;
+  pub use crate::operators::flat_map_with_concurrency::*;
+  pub use crate::operators::for_each_async::*;
   pub use crate::operators::group_by::*;
+  pub use crate::operators::group_join::*;
   pub use crate::operators::ignore_elements::*;
+  pub use crate::operators::join::*;
   pub use crate::operators::last::*;
+  pub use crate::operators::last_async::*;
   pub use crate::operators::map::*;
   pub use crate::operators::map_to_any::*;
   pub use crate::operators::materialize::*;
@@ -86,16 +140,21 @@ pub mod operators {
   pub use crate::operators::merge::*;
   pub use crate::operators::min::*;
   pub use crate::operators::observe_on::*;
+  pub use crate::operators::observe_on_bounded::*;
   pub use crate::operators::on_error_resume_next::*;
+  pub use crate::operators::pattern::*;
   pub use crate::operators::publish::*;
   pub use crate::operators::reduce::*;
   pub use crate::operators::ref_count::*;
+  pub use crate::operators::repeat::*;
   pub use crate::operators::replay::*;
   pub use crate::operators::retry::*;
   pub use crate::operators::retry_when::*;
   pub use crate::operators::sample::*;
   pub use crate::operators::scan::*;
   pub use crate::operators::sequence_equal::*;
+  pub use crate::operators::serialize::*;
+  pub use crate::operators::single_async::*;
   pub use crate::operators::skip::*;
   pub use crate::operators::skip_last::*;
   pub use crate::operators::skip_until::*;
@@ -104,24 +163,44 @@ pub mod operators {
   pub use crate::operators::subscribe_on::*;
   pub use crate::operators::sum::*;
   pub use crate::operators::sum_and_count::*;
+  pub use crate::operators::switch_map::*;
   pub use crate::operators::switch_on_next::*;
   pub use crate::operators::take::*;
   pub use crate::operators::take_last::*;
   pub use crate::operators::take_until::*;
   pub use crate::operators::take_while::*;
   pub use crate::operators::tap::*;
+  pub use crate::operators::to_blocking_iter::*;
   pub use crate::operators::to_vec::*;
+  pub use crate::operators::window::*;
+  pub use crate::operators::window_toggle::*;
   pub use crate::operators::window_with_count::*;
+  pub use crate::operators::with_latest_from::*;
   pub use crate::operators::zip::*;
 
+  #[cfg(not(feature = "web"))]
+  pub use crate::operators::buffer_with_time::*;
+  #[cfg(not(feature = "web"))]
+  pub use crate::operators::buffer_with_time_or_count::*;
   #[cfg(not(feature = "web"))]
   pub use crate::operators::debounce::*;
   #[cfg(not(feature = "web"))]
   pub use crate::operators::delay::*;
   #[cfg(not(feature = "web"))]
+  pub use crate::operators::sample_time::*;
+  #[cfg(not(feature = "web"))]
+  pub use crate::operators::throttle::*;
+  #[cfg(not(feature = "web"))]
   pub use crate::operators::time_interval::*;
   #[cfg(not(feature = "web"))]
   pub use crate::operators::timeout::*;
   #[cfg(not(feature = "web"))]
   pub use crate::operators::timestamp::*;
+  #[cfg(not(feature = "web"))]
+  pub use crate::operators::window_with_time::*;
+  #[cfg(not(feature = "web"))]
+  pub use crate::operators::window_with_time_or_count::*;
+
+  #[cfg(feature = "stream")]
+  pub use crate::operators::into_stream::*;
 }
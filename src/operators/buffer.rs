@@ -0,0 +1,133 @@
+use crate::internals::stream_controller::*;
+use crate::prelude::*;
+use std::{
+  marker::PhantomData,
+  sync::{Arc, RwLock},
+};
+
+#[derive(Clone)]
+pub struct Buffer<'a, Item, Boundary>
+where
+  Item: Clone + Send + Sync,
+  Boundary: Clone + Send + Sync,
+{
+  boundary: Observable<'a, Boundary>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Item, Boundary> Buffer<'a, Item, Boundary>
+where
+  Item: Clone + Send + Sync,
+  Boundary: Clone + Send + Sync,
+{
+  pub fn new(boundary: Observable<'a, Boundary>) -> Buffer<'a, Item, Boundary> {
+    Buffer { boundary, _item: PhantomData }
+  }
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Vec<Item>> {
+    let boundary = self.boundary.clone();
+    Observable::<Vec<Item>>::create(move |s| {
+      let results = Arc::new(RwLock::new(Vec::new()));
+      let sctl = StreamController::new(s);
+
+      let obs_boundary = {
+        let results_next = Arc::clone(&results);
+        let results_complete = Arc::clone(&results);
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, _| {
+            let vec = std::mem::take(&mut *results_next.write().unwrap());
+            sctl_next.sink_next(vec);
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |_| {
+            let vec = std::mem::take(&mut *results_complete.write().unwrap());
+            if !vec.is_empty() {
+              sctl_complete.sink_next(vec);
+            }
+            sctl_complete.sink_complete_force();
+          },
+        )
+      };
+
+      let obs_source = {
+        let results_next = Arc::clone(&results);
+        let results_complete = Arc::clone(&results);
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x| {
+            results_next.write().unwrap().push(x);
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |_| {
+            let vec = std::mem::take(&mut *results_complete.write().unwrap());
+            if !vec.is_empty() {
+              sctl_complete.sink_next(vec);
+            }
+            sctl_complete.sink_complete_force();
+          },
+        )
+      };
+
+      boundary.inner_subscribe(obs_boundary);
+      source.inner_subscribe(obs_source);
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn buffer<Boundary>(
+    &self,
+    boundary: Observable<'a, Boundary>,
+  ) -> Observable<'a, Vec<Item>>
+  where
+    Boundary: Clone + Send + Sync,
+  {
+    Buffer::new(boundary).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let boundary = subjects::Subject::<()>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj.observable().buffer(boundary.observable()).subscribe(
+        move |x| log.write().unwrap().push(x),
+        print_error!(),
+        print_complete!(),
+      );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    boundary.next(());
+    boundary.next(());
+    sbj.next(2);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1], vec![], vec![2]]
+    );
+  }
+}
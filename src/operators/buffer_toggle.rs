@@ -0,0 +1,206 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::{
+  collections::BTreeMap,
+  marker::PhantomData,
+  sync::{Arc, RwLock},
+};
+
+struct BufferToggleState<Item> {
+  buffers: BTreeMap<usize, Vec<Item>>,
+  serial: usize,
+}
+
+#[derive(Clone)]
+pub struct BufferToggle<'a, Item, Opening, Closing>
+where
+  Item: Clone + Send + Sync,
+  Opening: Clone + Send + Sync,
+  Closing: Clone + Send + Sync,
+{
+  openings: Observable<'a, Opening>,
+  closing_selector: FunctionWrapper<'a, Opening, Observable<'a, Closing>>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Item, Opening, Closing> BufferToggle<'a, Item, Opening, Closing>
+where
+  Item: Clone + Send + Sync,
+  Opening: Clone + Send + Sync,
+  Closing: Clone + Send + Sync,
+{
+  pub fn new<F>(
+    openings: Observable<'a, Opening>,
+    closing_selector: F,
+  ) -> BufferToggle<'a, Item, Opening, Closing>
+  where
+    F: Fn(Opening) -> Observable<'a, Closing> + Send + Sync + 'a,
+  {
+    BufferToggle {
+      openings,
+      closing_selector: FunctionWrapper::new(closing_selector),
+      _item: PhantomData,
+    }
+  }
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Vec<Item>> {
+    let openings = self.openings.clone();
+    let closing_selector = self.closing_selector.clone();
+
+    Observable::<Vec<Item>>::create(move |s| {
+      let state = Arc::new(RwLock::new(BufferToggleState {
+        buffers: BTreeMap::new(),
+        serial: 0,
+      }));
+      let sctl = StreamController::new(s);
+
+      let obs_openings = {
+        let state = Arc::clone(&state);
+        let closing_selector = closing_selector.clone();
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x| {
+            let id = {
+              let mut state = state.write().unwrap();
+              let id = state.serial;
+              state.serial += 1;
+              state.buffers.insert(id, Vec::new());
+              id
+            };
+
+            // the buffer is closed by the first item or the completion of
+            // its closing observable.
+            let close = {
+              let state = Arc::clone(&state);
+              let sctl = sctl_next.clone();
+              move |serial| {
+                sctl.upstream_abort_observe(&serial);
+                let vec = state.write().unwrap().buffers.remove(&id);
+                if let Some(vec) = vec {
+                  sctl.sink_next(vec);
+                }
+              }
+            };
+            let close_next = close.clone();
+            let sctl_closing_error = sctl_next.clone();
+            closing_selector
+              .call(x)
+              .inner_subscribe(sctl_next.new_observer(
+                move |serial, _| close_next(serial),
+                move |_, e| {
+                  sctl_closing_error.sink_error(e);
+                },
+                close,
+              ));
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+          },
+        )
+      };
+
+      let obs_source = {
+        let state_next = Arc::clone(&state);
+        let state_complete = Arc::clone(&state);
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x: Item| {
+            state_next
+              .write()
+              .unwrap()
+              .buffers
+              .values_mut()
+              .for_each(|vec| vec.push(x.clone()));
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |_| {
+            let buffers =
+              std::mem::take(&mut state_complete.write().unwrap().buffers);
+            buffers.into_values().for_each(|vec| {
+              sctl_complete.sink_next(vec);
+            });
+            sctl_complete.sink_complete_force();
+          },
+        )
+      };
+
+      openings.inner_subscribe(obs_openings);
+      source.inner_subscribe(obs_source);
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn buffer_toggle<Opening, Closing, F>(
+    &self,
+    openings: Observable<'a, Opening>,
+    closing_selector: F,
+  ) -> Observable<'a, Vec<Item>>
+  where
+    Opening: Clone + Send + Sync + 'a,
+    Closing: Clone + Send + Sync + 'a,
+    F: Fn(Opening) -> Observable<'a, Closing> + Send + Sync + 'a,
+  {
+    BufferToggle::new(openings, closing_selector).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let openings = subjects::Subject::<i32>::new();
+    let closings = [subjects::Subject::<()>::new(), subjects::Subject::new()];
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      let closings = closings.clone();
+      sbj
+        .observable()
+        .buffer_toggle(openings.observable(), move |n| {
+          closings[n as usize].observable()
+        })
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    openings.next(0);
+    sbj.next(1);
+    openings.next(1);
+    sbj.next(2);
+    closings[0].next(());
+    sbj.next(3);
+    closings[0].next(());
+    closings[1].complete();
+    sbj.next(4);
+    openings.next(0);
+    sbj.next(5);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![1, 2], vec![2, 3], vec![5]]
+    );
+  }
+}
@@ -1,6 +1,7 @@
 use crate::internals::stream_controller::*;
 use crate::prelude::*;
 use std::{
+  collections::VecDeque,
   marker::PhantomData,
   sync::{Arc, RwLock},
 };
@@ -11,6 +12,7 @@ where
   Item: Clone + Send + Sync,
 {
   count: usize,
+  skip: usize,
   _item: PhantomData<Item>,
 }
 
@@ -18,29 +20,37 @@ impl<'a, Item> BufferWithCount<Item>
 where
   Item: Clone + Send + Sync,
 {
-  pub fn new(count: usize) -> BufferWithCount<Item> {
+  pub fn new(count: usize, skip: usize) -> BufferWithCount<Item> {
     assert!(count > 0);
-    BufferWithCount { count, _item: PhantomData }
+    assert!(skip > 0);
+    BufferWithCount { count, skip, _item: PhantomData }
   }
   pub fn execute(
     &self,
     source: Observable<'a, Item>,
   ) -> Observable<'a, Vec<Item>> {
     let count = self.count;
+    let skip = self.skip;
     Observable::<Vec<Item>>::create(move |s| {
-      let results = Arc::new(RwLock::new(Vec::new()));
+      // a new buffer is opened every `skip` items, so buffers overlap when
+      // `skip` is less than `count`.
+      let n = Arc::new(RwLock::new(0usize));
+      let results = Arc::new(RwLock::new(VecDeque::<Vec<Item>>::new()));
       let sctl = StreamController::new(s);
 
       let sctl_f = sctl.clone();
       let results_f = Arc::clone(&results);
       let register = move |item: Item| {
         let vec = {
-          let mut vec = results_f.write().unwrap();
-          vec.push(item);
-          if vec.len() == count {
-            let v = vec.clone();
-            vec.clear();
-            Some(v)
+          let mut n = n.write().unwrap();
+          let mut results = results_f.write().unwrap();
+          if *n == 0 {
+            results.push_back(Vec::new());
+          }
+          *n = (*n + 1) % skip;
+          results.iter_mut().for_each(|vec| vec.push(item.clone()));
+          if results.front().is_some_and(|vec| vec.len() == count) {
+            results.pop_front()
           } else {
             None
           }
@@ -61,10 +71,12 @@ where
             sctl_error.sink_error(e);
           },
           move |serial| {
-            let vec = results.read().unwrap();
-            if vec.len() > 0 {
-              sctl_complete.sink_next(vec.clone());
-            }
+            let results = std::mem::take(&mut *results.write().unwrap());
+            results.into_iter().for_each(|vec| {
+              if !vec.is_empty() {
+                sctl_complete.sink_next(vec);
+              }
+            });
             sctl_complete.sink_complete(&serial);
           },
         ));
@@ -77,20 +89,27 @@ impl<'a, Item> Observable<'a, Item>
 where
   Item: Clone + Send + Sync,
 {
-  pub fn buffer_with_count(&self, count: usize) -> Observable<'a, Vec<Item>> {
-    BufferWithCount::new(count).execute(self.clone())
+  pub fn buffer_with_count(
+    &self,
+    count: usize,
+    skip: usize,
+  ) -> Observable<'a, Vec<Item>> {
+    BufferWithCount::new(count, skip).execute(self.clone())
   }
 }
 
 #[cfg(test)]
 mod test {
   use crate::prelude::*;
-  use std::{thread, time};
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
 
   #[test]
   fn basic() {
     observables::from_iter(0..10)
-      .buffer_with_count(3)
+      .buffer_with_count(3, 3)
       .subscribe(
         print_next_fmt!("{:?}"),
         print_error!(),
@@ -98,4 +117,48 @@ mod test {
       );
     thread::sleep(time::Duration::from_millis(1000));
   }
+
+  #[test]
+  fn sliding() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(0..5)
+        .buffer_with_count(3, 1)
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![
+        vec![0, 1, 2],
+        vec![1, 2, 3],
+        vec![2, 3, 4],
+        vec![3, 4],
+        vec![4]
+      ]
+    );
+  }
+
+  #[test]
+  fn skip_over() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(0..8)
+        .buffer_with_count(2, 3)
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1], vec![3, 4], vec![6, 7]]
+    );
+  }
 }
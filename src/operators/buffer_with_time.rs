@@ -0,0 +1,119 @@
+use crate::prelude::*;
+use scheduler::ITimedScheduler;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct BufferWithTime<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  buffer_op: operators::BufferWithTimeOrCount<'a, Scheduler, Item>,
+}
+
+impl<'a, Scheduler, Item> BufferWithTime<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+  Item: Clone + Send + Sync,
+{
+  pub fn new<SchedulerCreator>(
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> BufferWithTime<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    BufferWithTime {
+      buffer_op: operators::BufferWithTimeOrCount::new(
+        dur,
+        usize::MAX,
+        scheduler_ctor,
+      ),
+    }
+  }
+
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Vec<Item>> {
+    self.buffer_op.execute(source)
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn buffer_with_time<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Vec<Item>>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    BufferWithTime::new(dur, scheduler_ctor).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .buffer_with_time(
+          time::Duration::from_millis(100),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          junk_error!(),
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    ts.advance_by(time::Duration::from_millis(100));
+    ts.advance_by(time::Duration::from_millis(100));
+    sbj.next(2);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1], vec![], vec![2]]
+    );
+  }
+
+  #[test]
+  fn thread() {
+    observables::interval(
+      time::Duration::from_millis(30),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(10)
+    .buffer_with_time(
+      time::Duration::from_millis(100),
+      schedulers::new_thread_scheduler(),
+    )
+    .subscribe(
+      print_next_fmt!("{:?}"),
+      print_error!(),
+      print_complete!(),
+    );
+    thread::sleep(time::Duration::from_millis(500));
+  }
+}
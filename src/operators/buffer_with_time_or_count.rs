@@ -0,0 +1,243 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use scheduler::ITimedScheduler;
+use std::{
+  marker::PhantomData,
+  sync::{Arc, RwLock},
+  time::Duration,
+};
+
+struct BufferState<Item> {
+  buffer: Vec<Item>,
+  // bumped whenever the buffer is flushed by count, so that the timer of
+  // the previous period does not flush the new buffer early.
+  generation: usize,
+}
+
+#[derive(Clone)]
+pub struct BufferWithTimeOrCount<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  dur: Duration,
+  count: usize,
+  scheduler_ctor: FunctionWrapper<'a, (), Scheduler>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Scheduler, Item> BufferWithTimeOrCount<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+  Item: Clone + Send + Sync,
+{
+  pub fn new<SchedulerCreator>(
+    dur: Duration,
+    count: usize,
+    scheduler_ctor: SchedulerCreator,
+  ) -> BufferWithTimeOrCount<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    assert!(count > 0);
+    BufferWithTimeOrCount {
+      dur,
+      count,
+      scheduler_ctor: FunctionWrapper::new(move |_| scheduler_ctor()),
+      _item: PhantomData,
+    }
+  }
+
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Vec<Item>> {
+    let dur = self.dur;
+    let count = self.count;
+    let scheduler_ctor = self.scheduler_ctor.clone();
+
+    Observable::<Vec<Item>>::create(move |s| {
+      fn arm<'a, Scheduler, Item>(
+        dur: Duration,
+        scheduler: &Scheduler,
+        state: Arc<RwLock<BufferState<Item>>>,
+        sctl: StreamController<'a, Vec<Item>>,
+      ) -> Subscription<'a>
+      where
+        Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+        Item: Clone + Send + Sync,
+      {
+        let generation = state.read().unwrap().generation;
+        scheduler.schedule_periodic(dur, move || {
+          let vec = {
+            let mut state = state.write().unwrap();
+            if state.generation != generation {
+              return;
+            }
+            std::mem::take(&mut state.buffer)
+          };
+          sctl.sink_next(vec);
+        })
+      }
+
+      let sctl = StreamController::new(s);
+      let scheduler = scheduler_ctor.call(());
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let state = Arc::new(RwLock::new(BufferState {
+        buffer: Vec::new(),
+        generation: 0,
+      }));
+      let timer = Arc::new(RwLock::new(Some(arm(
+        dur,
+        &scheduler,
+        Arc::clone(&state),
+        sctl.clone(),
+      ))));
+
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+      let state_next = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          let vec = {
+            let mut state = state_next.write().unwrap();
+            state.buffer.push(x);
+            if state.buffer.len() >= count {
+              state.generation += 1;
+              Some(std::mem::take(&mut state.buffer))
+            } else {
+              None
+            }
+          };
+          if let Some(vec) = vec {
+            // the period restarts after a flush by count.
+            if let Some(t) = timer.write().unwrap().take() {
+              t.unsubscribe();
+            }
+            let t = arm(
+              dur,
+              &scheduler,
+              Arc::clone(&state_next),
+              sctl_next.clone(),
+            );
+            *timer.write().unwrap() = Some(t);
+            sctl_next.sink_next(vec);
+          }
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          let vec = std::mem::take(&mut state_complete.write().unwrap().buffer);
+          if !vec.is_empty() {
+            sctl_complete.sink_next(vec);
+          }
+          sctl_complete.sink_complete(&serial);
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn buffer_with_time_or_count<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    count: usize,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Vec<Item>>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    BufferWithTimeOrCount::new(dur, count, scheduler_ctor).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .buffer_with_time_or_count(
+          time::Duration::from_millis(100),
+          3,
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          junk_error!(),
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    ts.advance_by(time::Duration::from_millis(50));
+    sbj.next(1);
+    sbj.next(2);
+    sbj.next(3);
+    // the count flush at 50ms restarts the period.
+    ts.advance_by(time::Duration::from_millis(60));
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1, 2]]
+    );
+    ts.advance_by(time::Duration::from_millis(40));
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1, 2], vec![3]]
+    );
+
+    sbj.next(4);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1, 2], vec![3], vec![4]]
+    );
+  }
+
+  #[test]
+  fn thread() {
+    observables::interval(
+      time::Duration::from_millis(30),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(10)
+    .buffer_with_time_or_count(
+      time::Duration::from_millis(100),
+      2,
+      schedulers::new_thread_scheduler(),
+    )
+    .subscribe(
+      print_next_fmt!("{:?}"),
+      print_error!(),
+      print_complete!(),
+    );
+    thread::sleep(time::Duration::from_millis(500));
+  }
+}
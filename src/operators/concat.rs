@@ -1,5 +1,6 @@
 use crate::internals::stream_controller::*;
 use crate::prelude::*;
+use scheduler::IScheduler;
 use std::{
   collections::VecDeque,
   sync::{Arc, RwLock},
@@ -26,11 +27,19 @@ where
     )));
     Observable::create(move |s| {
       let sctl = StreamController::new(s);
+      let scheduler = schedulers::CurrentThreadScheduler::new();
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
 
       fn complete_and_next<'a, Item>(
         idx: usize,
         observables: Arc<RwLock<VecDeque<Observable<'a, Item>>>>,
         sctl: StreamController<'a, Item>,
+        scheduler: schedulers::CurrentThreadScheduler<'a>,
       ) where
         Item: Clone + Send + Sync,
       {
@@ -52,21 +61,27 @@ where
             sctl_error.sink_error(e);
           },
           move |_| {
-            complete_and_next(
-              idx + 1,
-              Arc::clone(&observables),
-              sctl_complete.clone(),
-            );
+            let observables = Arc::clone(&observables);
+            let sctl = sctl_complete.clone();
+            let scheduler_next = scheduler.clone();
+            scheduler.post(move || {
+              complete_and_next(
+                idx + 1,
+                Arc::clone(&observables),
+                sctl.clone(),
+                scheduler_next.clone(),
+              );
+            });
           },
         ));
       }
 
       {
-        let complete_and_next = complete_and_next.clone();
         let sctl_error = sctl.clone();
         let sctl_next = sctl.clone();
         let sctl_complete = sctl.clone();
         let observables = Arc::clone(&observables);
+        let scheduler = scheduler.clone();
         source.inner_subscribe(sctl.new_observer(
           move |_, x| {
             sctl_next.sink_next(x);
@@ -75,11 +90,17 @@ where
             sctl_error.sink_error(e);
           },
           move |_| {
-            complete_and_next(
-              0,
-              Arc::clone(&observables),
-              sctl_complete.clone(),
-            );
+            let observables = Arc::clone(&observables);
+            let sctl = sctl_complete.clone();
+            let scheduler_next = scheduler.clone();
+            scheduler.post(move || {
+              complete_and_next(
+                0,
+                Arc::clone(&observables),
+                sctl.clone(),
+                scheduler_next.clone(),
+              );
+            });
           },
         ));
       }
@@ -0,0 +1,99 @@
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ConcatMap<'a, In, Out>
+where
+  In: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  flatmap_op: operators::FlatMapWithConcurrency<'a, In, Out>,
+}
+
+impl<'a, In, Out> ConcatMap<'a, In, Out>
+where
+  In: Clone + Send + Sync + 'a,
+  Out: Clone + Send + Sync + 'a,
+{
+  pub fn new<F>(f: F) -> ConcatMap<'a, In, Out>
+  where
+    F: Fn(In) -> Observable<'a, Out> + Send + Sync + 'a,
+  {
+    ConcatMap {
+      flatmap_op: operators::FlatMapWithConcurrency::new(1, f),
+    }
+  }
+  pub fn execute(&self, source: Observable<'a, In>) -> Observable<'a, Out> {
+    self.flatmap_op.execute(source)
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn concat_map<Out, F>(&self, f: F) -> Observable<'a, Out>
+  where
+    F: Fn(Item) -> Observable<'a, Out> + Send + Sync + 'a,
+    Out: Clone + Send + Sync,
+  {
+    ConcatMap::new(f).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let inners = [subjects::Subject::new(), subjects::Subject::new()];
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let completed = Arc::new(RwLock::new(false));
+
+    {
+      let log = Arc::clone(&log);
+      let completed = Arc::clone(&completed);
+      let inners = inners.clone();
+      sbj
+        .observable()
+        .concat_map(move |x: usize| inners[x].observable())
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          move || *completed.write().unwrap() = true,
+        );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    inners[0].next(1);
+    // the second inner observable is not subscribed yet.
+    inners[1].next(100);
+    sbj.complete();
+    inners[0].next(2);
+    inners[0].complete();
+    inners[1].next(3);
+    assert!(!*completed.read().unwrap());
+    inners[1].complete();
+    assert!(*completed.read().unwrap());
+    assert_eq!(*log.read().unwrap(), vec![1, 2, 3]);
+  }
+
+  #[test]
+  fn sync_inner() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(0..3)
+        .concat_map(|x| observables::from_iter(0..x))
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(*log.read().unwrap(), vec![0, 0, 1]);
+  }
+}
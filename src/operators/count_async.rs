@@ -0,0 +1,38 @@
+use crate::internals::awaitable::*;
+use crate::prelude::*;
+
+pub type CountAsync<'a> = Awaitable<'a, usize>;
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn count_async(&self) -> CountAsync<'a> {
+    let source = self.count();
+    Awaitable::new(move |sink| {
+      let sink_error = sink.clone();
+      source.subscribe(
+        move |n| sink.resolve(Ok(n)),
+        move |e| sink_error.resolve(Err(e)),
+        || {},
+      )
+    })
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+
+  #[tokio::test]
+  async fn basic() {
+    assert_eq!(
+      observables::from_iter(0..5).count_async().await.unwrap(),
+      5
+    );
+    assert_eq!(
+      observables::empty::<i32>().count_async().await.unwrap(),
+      0
+    );
+  }
+}
@@ -1,13 +1,20 @@
 use crate::internals::{function_wrapper::*, stream_controller::*};
 use crate::prelude::*;
-use scheduler::IScheduler;
+use scheduler::ITimedScheduler;
 use std::sync::{Arc, RwLock};
-use std::{marker::PhantomData, thread, time::Duration};
+use std::{marker::PhantomData, time::Duration};
+
+struct DebounceState<'a, Item> {
+  value: Option<Item>,
+  // bumped on every item, so that only the timer of the latest item emits.
+  generation: usize,
+  timer: Option<Subscription<'a>>,
+}
 
 #[derive(Clone)]
 pub struct Debounce<'a, Scheduler, Item>
 where
-  Scheduler: IScheduler<'a> + Clone + Send + Sync,
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
   Item: Clone + Send + Sync,
 {
   dur: Duration,
@@ -18,7 +25,7 @@ where
 
 impl<'a, Scheduler, Item> Debounce<'a, Scheduler, Item>
 where
-  Scheduler: IScheduler<'a> + Clone + Send + Sync,
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
   Item: Clone + Send + Sync,
 {
   pub fn new<SchedulerCreator>(
@@ -40,7 +47,11 @@ where
     let scheduler_ctor = self.scheduler_ctor.clone();
 
     Observable::<Item>::create(move |s| {
-      let value = Arc::new(RwLock::new(None::<Item>));
+      let state = Arc::new(RwLock::new(DebounceState {
+        value: None::<Item>,
+        generation: 0,
+        timer: None,
+      }));
 
       let sctl = StreamController::new(s);
 
@@ -51,36 +62,66 @@ where
           scheduler.abort();
         });
       }
-      {
-        let sctl = sctl.clone();
-        let value = Arc::clone(&value);
-        scheduler.post(move || {
-          while sctl.is_subscribed() {
-            thread::sleep(dur);
-            let value = {
-              let mut value = value.write().unwrap();
-              let v = value.clone();
-              *value = None;
-              v
-            };
-            if let Some(value) = value {
-              sctl.sink_next(value);
-            }
-          }
-        })
-      }
 
+      let state_next = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+      let sctl_next = sctl.clone();
       let sctl_error = sctl.clone();
       let sctl_complete = sctl.clone();
 
       source.inner_subscribe(sctl.new_observer(
         move |_, x| {
-          *value.write().unwrap() = Some(x);
+          // every item restarts the quiet period.
+          let (generation, prev) = {
+            let mut state = state_next.write().unwrap();
+            state.value = Some(x);
+            state.generation += 1;
+            (state.generation, state.timer.take())
+          };
+          if let Some(prev) = prev {
+            prev.unsubscribe();
+          }
+
+          let timer = {
+            let state = Arc::clone(&state_next);
+            let sctl = sctl_next.clone();
+            scheduler.schedule_after(dur, move || {
+              let value = {
+                let mut state = state.write().unwrap();
+                if state.generation != generation {
+                  return;
+                }
+                state.timer = None;
+                state.value.take()
+              };
+              if let Some(value) = value {
+                sctl.sink_next(value);
+              }
+            })
+          };
+          let mut state = state_next.write().unwrap();
+          if state.generation == generation && state.value.is_some() {
+            state.timer = Some(timer);
+          }
         },
         move |_, e| {
           sctl_error.sink_error(e);
         },
-        move |serial| sctl_complete.sink_complete(&serial),
+        move |serial| {
+          // the pending item is not lost on completion.
+          let (value, timer) = {
+            let mut state = state_complete.write().unwrap();
+            state.generation += 1;
+            (state.value.take(), state.timer.take())
+          };
+          if let Some(timer) = timer {
+            timer.unsubscribe();
+          }
+          if let Some(value) = value {
+            sctl_complete.sink_next(value);
+          }
+          sctl_complete.sink_complete(&serial);
+        },
       ));
     })
   }
@@ -96,7 +137,7 @@ where
     scheduler_ctor: SchedulerCreator,
   ) -> Observable<'a, Item>
   where
-    Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
     SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
   {
     Debounce::new(dur, scheduler_ctor).execute(self.clone())
@@ -106,19 +147,60 @@ where
 #[cfg(all(test, not(feature = "web")))]
 mod test {
   use crate::prelude::*;
-  use std::{thread, time};
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
 
   #[test]
   fn basic() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .debounce(
+          time::Duration::from_millis(100),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          junk_error!(),
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    ts.advance_by(time::Duration::from_millis(50));
+    sbj.next(1);
+    ts.advance_by(time::Duration::from_millis(50));
+    sbj.next(2);
+    ts.advance_by(time::Duration::from_millis(99));
+    assert_eq!(*log.read().unwrap(), Vec::<i32>::new());
+    ts.advance_by(time::Duration::from_millis(1));
+    assert_eq!(*log.read().unwrap(), vec![2]);
+
+    sbj.next(3);
+    ts.advance_by(time::Duration::from_millis(150));
+    sbj.next(4);
+    sbj.complete();
+    assert_eq!(*log.read().unwrap(), vec![2, 3, 4]);
+  }
+
+  #[test]
+  fn thread() {
     observables::interval(
       time::Duration::from_millis(10),
       schedulers::new_thread_scheduler(),
     )
+    .take(20)
     .debounce(
       time::Duration::from_millis(100),
       schedulers::new_thread_scheduler(),
     )
-    .take(5)
     .subscribe(
       print_next_fmt!("{}"),
       print_error!(),
@@ -0,0 +1,182 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::sync::{Arc, RwLock};
+
+struct DebounceWithState<Item> {
+  value: Option<Item>,
+  // serial of the observer watching the quiet period of the latest item.
+  trigger: Option<i32>,
+}
+
+#[derive(Clone)]
+pub struct DebounceWith<'a, Item, Trigger>
+where
+  Item: Clone + Send + Sync,
+  Trigger: Clone + Send + Sync,
+{
+  selector: FunctionWrapper<'a, Item, Observable<'a, Trigger>>,
+}
+
+impl<'a, Item, Trigger> DebounceWith<'a, Item, Trigger>
+where
+  Item: Clone + Send + Sync,
+  Trigger: Clone + Send + Sync,
+{
+  pub fn new<F>(selector: F) -> DebounceWith<'a, Item, Trigger>
+  where
+    F: Fn(Item) -> Observable<'a, Trigger> + Send + Sync + 'a,
+  {
+    DebounceWith { selector: FunctionWrapper::new(selector) }
+  }
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    let selector = self.selector.clone();
+
+    Observable::<Item>::create(move |s| {
+      let state = Arc::new(RwLock::new(DebounceWithState {
+        value: None::<Item>,
+        trigger: None,
+      }));
+
+      let sctl = StreamController::new(s);
+
+      let selector = selector.clone();
+      let state_next = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x: Item| {
+          // the first item or the completion of the trigger ends the quiet
+          // period.
+          let fire = {
+            let state = Arc::clone(&state_next);
+            let sctl = sctl_next.clone();
+            move |serial| {
+              sctl.upstream_abort_observe(&serial);
+              let value = {
+                let mut state = state.write().unwrap();
+                if state.trigger != Some(serial) {
+                  return;
+                }
+                state.trigger = None;
+                state.value.take()
+              };
+              if let Some(value) = value {
+                sctl.sink_next(value);
+              }
+            }
+          };
+          let fire_next = fire.clone();
+          let sctl_trigger_error = sctl_next.clone();
+          let (serial, observer) = sctl_next.new_observer_with_serial(
+            move |serial, _: Trigger| fire_next(serial),
+            move |_, e| {
+              sctl_trigger_error.sink_error(e);
+            },
+            fire,
+          );
+
+          let prev = {
+            let mut state = state_next.write().unwrap();
+            state.value = Some(x.clone());
+            state.trigger.replace(serial)
+          };
+          if let Some(prev) = prev {
+            sctl_next.upstream_abort_observe(&prev);
+          }
+          selector.call(x).inner_subscribe(observer);
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          // the pending item is not lost on completion.
+          let (value, trigger) = {
+            let mut state = state_complete.write().unwrap();
+            (state.value.take(), state.trigger.take())
+          };
+          if let Some(trigger) = trigger {
+            sctl_complete.upstream_abort_observe(&trigger);
+          }
+          if let Some(value) = value {
+            sctl_complete.sink_next(value);
+          }
+          sctl_complete.sink_complete(&serial);
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn debounce_with<Trigger, F>(&self, selector: F) -> Observable<'a, Item>
+  where
+    Trigger: Clone + Send + Sync + 'a,
+    F: Fn(Item) -> Observable<'a, Trigger> + Send + Sync + 'a,
+  {
+    DebounceWith::new(selector).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let triggers = [
+      subjects::Subject::<()>::new(),
+      subjects::Subject::new(),
+      subjects::Subject::new(),
+    ];
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      let triggers = triggers.clone();
+      sbj
+        .observable()
+        .debounce_with(move |x: usize| triggers[x % 3].observable())
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    // the quiet period of 0 was cut off by 1.
+    triggers[0].next(());
+    assert_eq!(
+      *log.read().unwrap(),
+      Vec::<usize>::new()
+    );
+    triggers[1].next(());
+    assert_eq!(*log.read().unwrap(), vec![1]);
+
+    sbj.next(2);
+    triggers[2].complete();
+    sbj.next(3);
+    sbj.complete();
+    assert_eq!(*log.read().unwrap(), vec![1, 2, 3]);
+  }
+
+  #[test]
+  fn immediate() {
+    observables::from_iter(0..3)
+      .debounce_with(|_| observables::just(()))
+      .subscribe(
+        print_next_fmt!("{}"),
+        print_error!(),
+        print_complete!(),
+      );
+  }
+}
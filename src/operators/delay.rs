@@ -1,41 +1,75 @@
-use crate::internals::stream_controller::*;
+use crate::internals::{function_wrapper::*, stream_controller::*};
 use crate::prelude::*;
-use std::{marker::PhantomData, thread, time::Duration};
+use scheduler::ITimedScheduler;
+use std::{marker::PhantomData, time::Duration};
 
 #[derive(Clone)]
-pub struct Delay<Item>
+pub struct Delay<'a, Scheduler, Item>
 where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
   Item: Clone + Send + Sync,
 {
   dur: Duration,
+  scheduler_ctor: FunctionWrapper<'a, (), Scheduler>,
   _item: PhantomData<Item>,
 }
 
-impl<'a, Item> Delay<Item>
+impl<'a, Scheduler, Item> Delay<'a, Scheduler, Item>
 where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
   Item: Clone + Send + Sync,
 {
-  pub fn new(dur: Duration) -> Delay<Item> {
-    Delay { dur, _item: PhantomData }
+  pub fn new<SchedulerCreator>(
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Delay<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    Delay {
+      dur,
+      scheduler_ctor: FunctionWrapper::new(move |_| scheduler_ctor()),
+      _item: PhantomData,
+    }
   }
   pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
     let dur = self.dur;
+    let scheduler_ctor = self.scheduler_ctor.clone();
 
     Observable::<Item>::create(move |s| {
       let sctl = StreamController::new(s);
+
+      let scheduler = scheduler_ctor.call(());
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
       let sctl_next = sctl.clone();
       let sctl_error = sctl.clone();
       let sctl_complete = sctl.clone();
 
+      let scheduler_next = scheduler.clone();
+      let scheduler_complete = scheduler.clone();
+
       source.inner_subscribe(sctl.new_observer(
-        move |_, x| {
-          thread::sleep(dur);
-          sctl_next.sink_next(x);
+        move |_, x: Item| {
+          let sctl_next = sctl_next.clone();
+          scheduler_next.schedule_after(dur, move || {
+            sctl_next.sink_next(x.clone());
+          });
         },
         move |_, e| {
           sctl_error.sink_error(e);
         },
-        move |serial| sctl_complete.sink_complete(&serial),
+        move |serial| {
+          let sctl_complete = sctl_complete.clone();
+          scheduler_complete.schedule_after(dur, move || {
+            sctl_complete.sink_complete(&serial);
+          });
+        },
       ));
     })
   }
@@ -45,8 +79,16 @@ impl<'a, Item> Observable<'a, Item>
 where
   Item: Clone + Send + Sync,
 {
-  pub fn delay(&self, dur: Duration) -> Observable<'a, Item> {
-    Delay::new(dur).execute(self.clone())
+  pub fn delay<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    Delay::new(dur, scheduler_ctor).execute(self.clone())
   }
 }
 
@@ -66,7 +108,11 @@ mod test {
       s.complete();
     });
 
-    o.delay(time::Duration::from_millis(1000)).subscribe(
+    o.delay(
+      time::Duration::from_millis(1000),
+      schedulers::default_scheduler(),
+    )
+    .subscribe(
       print_next_fmt!("{}"),
       print_error!(),
       print_complete!(),
@@ -0,0 +1,196 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::{
+  collections::HashSet,
+  hash::Hash,
+  sync::{Arc, RwLock},
+};
+
+#[derive(Clone)]
+pub struct Distinct<'a, Item, Key>
+where
+  Item: Clone + Send + Sync,
+  Key: Clone + Send + Sync + Eq + Hash + 'a,
+{
+  // returns the item along with its key, so it is not cloned for the call.
+  key_f: FunctionWrapper<'a, Item, (Item, Key)>,
+  flushes: Option<Observable<'a, ()>>,
+}
+
+impl<'a, Item, Key> Distinct<'a, Item, Key>
+where
+  Item: Clone + Send + Sync,
+  Key: Clone + Send + Sync + Eq + Hash + 'a,
+{
+  pub fn new<F>(
+    key_f: F,
+    flushes: Option<Observable<'a, ()>>,
+  ) -> Distinct<'a, Item, Key>
+  where
+    F: Fn(&Item) -> Key + Send + Sync + 'a,
+  {
+    Distinct {
+      key_f: FunctionWrapper::new(move |x| {
+        let key = key_f(&x);
+        (x, key)
+      }),
+      flushes,
+    }
+  }
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    let key_f = self.key_f.clone();
+    let flushes = self.flushes.clone();
+
+    Observable::<Item>::create(move |s| {
+      let key_f = key_f.clone();
+      let seen = Arc::new(RwLock::new(HashSet::<Key>::new()));
+      let sctl = StreamController::new(s);
+
+      // every notification from `flushes` clears the seen-set.
+      if let Some(flushes) = &flushes {
+        let seen = Arc::clone(&seen);
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        flushes.inner_subscribe(sctl.new_observer(
+          move |_, _| {
+            seen.write().unwrap().clear();
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+          },
+        ));
+      }
+
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x: Item| {
+          let (x, key) = key_f.call(x);
+          if seen.write().unwrap().insert(key) {
+            sctl_next.sink_next(x);
+          }
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |_| sctl_complete.sink_complete_force(), // flushes also unsubscribe
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn distinct(&self) -> Observable<'a, Item>
+  where
+    Item: Eq + Hash,
+  {
+    Distinct::new(|x: &Item| x.clone(), None).execute(self.clone())
+  }
+
+  pub fn distinct_by<Key, F>(&self, key_f: F) -> Observable<'a, Item>
+  where
+    Key: Clone + Send + Sync + Eq + Hash + 'a,
+    F: Fn(&Item) -> Key + Send + Sync + 'a,
+  {
+    Distinct::new(key_f, None).execute(self.clone())
+  }
+
+  pub fn distinct_with_flush<Flush>(
+    &self,
+    flushes: Observable<'a, Flush>,
+  ) -> Observable<'a, Item>
+  where
+    Item: Eq + Hash,
+    Flush: Clone + Send + Sync,
+  {
+    Distinct::new(
+      |x: &Item| x.clone(),
+      Some(flushes.map(|_| ())),
+    )
+    .execute(self.clone())
+  }
+
+  pub fn distinct_by_with_flush<Key, Flush, F>(
+    &self,
+    key_f: F,
+    flushes: Observable<'a, Flush>,
+  ) -> Observable<'a, Item>
+  where
+    Key: Clone + Send + Sync + Eq + Hash + 'a,
+    Flush: Clone + Send + Sync,
+    F: Fn(&Item) -> Key + Send + Sync + 'a,
+  {
+    Distinct::new(key_f, Some(flushes.map(|_| ()))).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(vec![1, 2, 1, 3, 2, 4, 1].into_iter())
+        .distinct()
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(*log.read().unwrap(), vec![1, 2, 3, 4]);
+  }
+
+  #[test]
+  fn by_key() {
+    observables::from_iter(vec!["apple", "avocado", "banana"].into_iter())
+      .distinct_by(|x| x.chars().next())
+      .subscribe(
+        print_next_fmt!("{}"),
+        print_error!(),
+        print_complete!(),
+      );
+  }
+
+  #[test]
+  fn flush() {
+    let sbj = subjects::Subject::new();
+    let flushes = subjects::Subject::<()>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .distinct_with_flush(flushes.observable())
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+
+    sbj.next(1);
+    sbj.next(2);
+    sbj.next(1);
+    flushes.next(());
+    sbj.next(1);
+    sbj.next(2);
+    flushes.complete();
+    sbj.next(1);
+    sbj.complete();
+    assert_eq!(*log.read().unwrap(), vec![1, 2, 1, 2]);
+  }
+}
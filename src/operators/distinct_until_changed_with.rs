@@ -0,0 +1,130 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone)]
+pub struct DistinctUntilChangedWith<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  comparator: FunctionWrapper<'a, (Item, Item), bool>,
+}
+
+impl<'a, Item> DistinctUntilChangedWith<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn new<F>(comparator: F) -> DistinctUntilChangedWith<'a, Item>
+  where
+    F: Fn(&Item, &Item) -> bool + Send + Sync + 'a,
+  {
+    DistinctUntilChangedWith {
+      comparator: FunctionWrapper::new(move |(a, b)| comparator(&a, &b)),
+    }
+  }
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    let comparator = self.comparator.clone();
+
+    Observable::<Item>::create(move |s| {
+      let comparator = comparator.clone();
+      let last = Arc::new(RwLock::new(Option::<Item>::None));
+
+      let sctl = StreamController::new(s);
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x: Item| {
+          let last_x = last.read().unwrap().clone();
+          // the comparator returns true when both items are the same.
+          let changed = match last_x {
+            Some(last_x) => !comparator.call((last_x, x.clone())),
+            None => true,
+          };
+          if changed {
+            *last.write().unwrap() = Some(x.clone());
+            sctl_next.sink_next(x);
+          }
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| sctl_complete.sink_complete(&serial),
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn distinct_until_changed_with<F>(
+    &self,
+    comparator: F,
+  ) -> Observable<'a, Item>
+  where
+    F: Fn(&Item, &Item) -> bool + Send + Sync + 'a,
+  {
+    DistinctUntilChangedWith::new(comparator).execute(self.clone())
+  }
+
+  pub fn distinct_until_changed_by<Key, F>(
+    &self,
+    key_f: F,
+  ) -> Observable<'a, Item>
+  where
+    Key: PartialEq,
+    F: Fn(&Item) -> Key + Send + Sync + 'a,
+  {
+    DistinctUntilChangedWith::new(move |a, b| key_f(a) == key_f(b))
+      .execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn with_comparator() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(vec![1.0, 1.05, 1.5, 1.52, 1.0].into_iter())
+        .distinct_until_changed_with(|a: &f64, b: &f64| (a - b).abs() < 0.1)
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![1.0, 1.5, 1.0]
+    );
+  }
+
+  #[test]
+  fn by_key() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(
+        vec![(0, 'a'), (0, 'b'), (1, 'c'), (0, 'd')].into_iter(),
+      )
+      .distinct_until_changed_by(|x| x.0)
+      .subscribe(
+        move |x| log.write().unwrap().push(x.1),
+        print_error!(),
+        print_complete!(),
+      );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec!['a', 'c', 'd']
+    );
+  }
+}
@@ -0,0 +1,131 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone)]
+pub struct ExhaustMap<'a, In, Out>
+where
+  In: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  exhaustmap_f: FunctionWrapper<'a, In, Observable<'a, Out>>,
+}
+
+impl<'a, In, Out> ExhaustMap<'a, In, Out>
+where
+  In: Clone + Send + Sync + 'a,
+  Out: Clone + Send + Sync + 'a,
+{
+  pub fn new<F>(f: F) -> ExhaustMap<'a, In, Out>
+  where
+    F: Fn(In) -> Observable<'a, Out> + Send + Sync + 'a,
+  {
+    ExhaustMap { exhaustmap_f: FunctionWrapper::new(f) }
+  }
+  pub fn execute(&self, source: Observable<'a, In>) -> Observable<'a, Out> {
+    let f = self.exhaustmap_f.clone();
+
+    Observable::create(move |s| {
+      let f = f.clone();
+      let active = Arc::new(RwLock::new(false));
+
+      let sctl = StreamController::new(s);
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          // items are ignored while an inner observable is running.
+          {
+            let mut active = active.write().unwrap();
+            if *active {
+              return;
+            }
+            *active = true;
+          }
+
+          let active_complete = Arc::clone(&active);
+          let sctl_next_next = sctl_next.clone();
+          let sctl_next_error = sctl_next.clone();
+          let sctl_next_complete = sctl_next.clone();
+
+          f.call(x).inner_subscribe(sctl_next.new_observer(
+            move |_, xx| {
+              sctl_next_next.sink_next(xx);
+            },
+            move |_, ee| {
+              sctl_next_error.sink_error(ee);
+            },
+            move |serial| {
+              *active_complete.write().unwrap() = false;
+              sctl_next_complete.sink_complete(&serial);
+            },
+          ));
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          sctl_complete.sink_complete(&serial);
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn exhaust_map<Out, F>(&self, f: F) -> Observable<'a, Out>
+  where
+    F: Fn(Item) -> Observable<'a, Out> + Send + Sync + 'a,
+    Out: Clone + Send + Sync,
+  {
+    ExhaustMap::new(f).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let inners = [
+      subjects::Subject::new(),
+      subjects::Subject::new(),
+      subjects::Subject::new(),
+    ];
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      let inners = inners.clone();
+      sbj
+        .observable()
+        .exhaust_map(move |x: usize| inners[x].observable())
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    inners[0].next(1);
+    // ignored while the first inner observable is running.
+    sbj.next(1);
+    inners[1].next(100);
+    inners[0].next(2);
+    inners[0].complete();
+    sbj.next(2);
+    inners[2].next(3);
+    inners[2].complete();
+    sbj.complete();
+    assert_eq!(*log.read().unwrap(), vec![1, 2, 3]);
+  }
+}
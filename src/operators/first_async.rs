@@ -0,0 +1,49 @@
+use crate::internals::awaitable::*;
+use crate::prelude::*;
+
+pub type FirstAsync<'a, Item> = Awaitable<'a, Item>;
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn first_async(&self) -> FirstAsync<'a, Item> {
+    let source = self.first();
+    Awaitable::new(move |sink| {
+      let sink_error = sink.clone();
+      let sink_complete = sink.clone();
+      source.subscribe(
+        move |x| sink.resolve(Ok(x)),
+        move |e| sink_error.resolve(Err(e)),
+        move || sink_complete.resolve(Err(no_elements_error())),
+      )
+    })
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use std::time;
+
+  #[tokio::test]
+  async fn basic() {
+    assert_eq!(
+      observables::from_iter(3..).first_async().await.unwrap(),
+      3
+    );
+    assert!(observables::empty::<i32>().first_async().await.is_err());
+  }
+
+  #[tokio::test]
+  async fn thread() {
+    let x = observables::interval(
+      time::Duration::from_millis(50),
+      schedulers::new_thread_scheduler(),
+    )
+    .skip(2)
+    .first_async()
+    .await;
+    assert_eq!(x.unwrap(), 2);
+  }
+}
@@ -0,0 +1,258 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use scheduler::IScheduler;
+use std::{
+  collections::VecDeque,
+  sync::{Arc, RwLock},
+};
+
+struct FlatMapWithConcurrencyState<In> {
+  queue: VecDeque<In>,
+  active: usize,
+  source_completed: bool,
+}
+
+#[derive(Clone)]
+pub struct FlatMapWithConcurrency<'a, In, Out>
+where
+  In: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  max: usize,
+  flatmap_f: FunctionWrapper<'a, In, Observable<'a, Out>>,
+}
+
+impl<'a, In, Out> FlatMapWithConcurrency<'a, In, Out>
+where
+  In: Clone + Send + Sync + 'a,
+  Out: Clone + Send + Sync + 'a,
+{
+  pub fn new<F>(max: usize, f: F) -> FlatMapWithConcurrency<'a, In, Out>
+  where
+    F: Fn(In) -> Observable<'a, Out> + Send + Sync + 'a,
+  {
+    assert!(max > 0);
+    FlatMapWithConcurrency { max, flatmap_f: FunctionWrapper::new(f) }
+  }
+  pub fn execute(&self, source: Observable<'a, In>) -> Observable<'a, Out> {
+    let max = self.max;
+    let f = self.flatmap_f.clone();
+
+    Observable::create(move |s| {
+      // runs the next queued item on a slot that is already counted in
+      // `active`, or releases the slot when the queue is empty.
+      fn subscribe_next<'a, In, Out>(
+        f: FunctionWrapper<'a, In, Observable<'a, Out>>,
+        state: Arc<RwLock<FlatMapWithConcurrencyState<In>>>,
+        sctl: StreamController<'a, Out>,
+        scheduler: schedulers::CurrentThreadScheduler<'a>,
+      ) where
+        In: Clone + Send + Sync + 'a,
+        Out: Clone + Send + Sync + 'a,
+      {
+        let x = {
+          let mut state = state.write().unwrap();
+          match state.queue.pop_front() {
+            Some(x) => x,
+            None => {
+              state.active -= 1;
+              if state.source_completed && state.active == 0 {
+                drop(state);
+                sctl.sink_complete_force();
+              }
+              return;
+            }
+          }
+        };
+
+        let f_next = f.clone();
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+
+        f.call(x).inner_subscribe(sctl.new_observer(
+          move |_, xx| {
+            sctl_next.sink_next(xx);
+          },
+          move |_, ee| {
+            sctl_error.sink_error(ee);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+            let f = f_next.clone();
+            let state = Arc::clone(&state);
+            let sctl = sctl_complete.clone();
+            let scheduler_next = scheduler.clone();
+            scheduler.post(move || {
+              subscribe_next(
+                f.clone(),
+                Arc::clone(&state),
+                sctl.clone(),
+                scheduler_next.clone(),
+              );
+            });
+          },
+        ));
+      }
+
+      let state = Arc::new(RwLock::new(
+        FlatMapWithConcurrencyState {
+          queue: VecDeque::new(),
+          active: 0,
+          source_completed: false,
+        },
+      ));
+
+      let sctl = StreamController::new(s);
+      let scheduler = schedulers::CurrentThreadScheduler::new();
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let f = f.clone();
+      let state_next = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          // items are queued while `max` inner observables are running.
+          let start = {
+            let mut state = state_next.write().unwrap();
+            state.queue.push_back(x);
+            if state.active < max {
+              state.active += 1;
+              true
+            } else {
+              false
+            }
+          };
+          if start {
+            subscribe_next(
+              f.clone(),
+              Arc::clone(&state_next),
+              sctl_next.clone(),
+              scheduler.clone(),
+            );
+          }
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          sctl_complete.upstream_abort_observe(&serial);
+          let done = {
+            let mut state = state_complete.write().unwrap();
+            state.source_completed = true;
+            state.active == 0
+          };
+          if done {
+            sctl_complete.sink_complete_force();
+          }
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn flat_map_with_concurrency<Out, F>(
+    &self,
+    max: usize,
+    f: F,
+  ) -> Observable<'a, Out>
+  where
+    F: Fn(Item) -> Observable<'a, Out> + Send + Sync + 'a,
+    Out: Clone + Send + Sync,
+  {
+    FlatMapWithConcurrency::new(max, f).execute(self.clone())
+  }
+}
+
+impl<'a, Item> Observable<'a, Observable<'a, Item>>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn merge_all(&self, max: usize) -> Observable<'a, Item> {
+    FlatMapWithConcurrency::new(max, |x| x).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let inners = [
+      subjects::Subject::new(),
+      subjects::Subject::new(),
+      subjects::Subject::new(),
+    ];
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let completed = Arc::new(RwLock::new(false));
+
+    {
+      let log = Arc::clone(&log);
+      let completed = Arc::clone(&completed);
+      let inners = inners.clone();
+      sbj
+        .observable()
+        .flat_map_with_concurrency(2, move |x: usize| {
+          inners[x].observable()
+        })
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          move || *completed.write().unwrap() = true,
+        );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    sbj.next(2);
+    inners[0].next(1);
+    inners[1].next(2);
+    // the third inner observable waits for a free slot.
+    inners[2].next(100);
+    inners[1].complete();
+    inners[2].next(3);
+    sbj.complete();
+    inners[0].next(4);
+    inners[0].complete();
+    assert!(!*completed.read().unwrap());
+    inners[2].complete();
+    assert!(*completed.read().unwrap());
+    assert_eq!(*log.read().unwrap(), vec![1, 2, 3, 4]);
+  }
+
+  #[test]
+  fn merge_all() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(0..4)
+        .map(|x| observables::from_iter(0..x))
+        .merge_all(1)
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![0, 0, 1, 0, 1, 2]
+    );
+  }
+}
@@ -0,0 +1,69 @@
+use crate::internals::awaitable::*;
+use crate::prelude::*;
+
+pub type ForEachAsync<'a> = Awaitable<'a, ()>;
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn for_each_async<F>(&self, f: F) -> ForEachAsync<'a>
+  where
+    F: Fn(Item) + Send + Sync + 'a,
+  {
+    let source = self.clone();
+    Awaitable::new(move |sink| {
+      let sink_complete = sink.clone();
+      source.subscribe(
+        f,
+        move |e| sink.resolve(Err(e)),
+        move || sink_complete.resolve(Ok(())),
+      )
+    })
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    time,
+  };
+
+  #[tokio::test]
+  async fn basic() {
+    let sum = Arc::new(RwLock::new(0));
+    let r = {
+      let sum = Arc::clone(&sum);
+      observables::from_iter(0..5)
+        .for_each_async(move |x| *sum.write().unwrap() += x)
+        .await
+    };
+    assert!(r.is_ok());
+    assert_eq!(*sum.read().unwrap(), 10);
+  }
+
+  #[tokio::test]
+  async fn drop_unsubscribes() {
+    let count = Arc::new(RwLock::new(0));
+    let fut = {
+      let count = Arc::clone(&count);
+      observables::interval(
+        time::Duration::from_millis(20),
+        schedulers::new_thread_scheduler(),
+      )
+      .for_each_async(move |_| *count.write().unwrap() += 1)
+    };
+    assert!(
+      tokio::time::timeout(time::Duration::from_millis(110), fut)
+        .await
+        .is_err()
+    );
+
+    let n = *count.read().unwrap();
+    assert!(n > 0);
+    tokio::time::sleep(time::Duration::from_millis(100)).await;
+    assert_eq!(*count.read().unwrap(), n);
+  }
+}
@@ -0,0 +1,310 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::{
+  collections::BTreeMap,
+  sync::{Arc, RwLock},
+};
+
+struct GroupJoinState<'a, Right>
+where
+  Right: Clone + Send + Sync,
+{
+  windows: BTreeMap<usize, subjects::Subject<'a, Right>>,
+  right: BTreeMap<usize, Right>,
+  left_serial: usize,
+  right_serial: usize,
+}
+
+#[derive(Clone)]
+pub struct GroupJoin<'a, Left, Right, LeftDur, RightDur, Out>
+where
+  Left: Clone + Send + Sync,
+  Right: Clone + Send + Sync,
+  LeftDur: Clone + Send + Sync,
+  RightDur: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  right: Observable<'a, Right>,
+  left_duration_f: FunctionWrapper<'a, Left, Observable<'a, LeftDur>>,
+  right_duration_f: FunctionWrapper<'a, Right, Observable<'a, RightDur>>,
+  result_f: FunctionWrapper<'a, (Left, Observable<'a, Right>), Out>,
+}
+
+impl<'a, Left, Right, LeftDur, RightDur, Out>
+  GroupJoin<'a, Left, Right, LeftDur, RightDur, Out>
+where
+  Left: Clone + Send + Sync + 'a,
+  Right: Clone + Send + Sync + 'a,
+  LeftDur: Clone + Send + Sync + 'a,
+  RightDur: Clone + Send + Sync + 'a,
+  Out: Clone + Send + Sync + 'a,
+{
+  pub fn new<LeftDurF, RightDurF, ResultF>(
+    right: Observable<'a, Right>,
+    left_duration_selector: LeftDurF,
+    right_duration_selector: RightDurF,
+    result_selector: ResultF,
+  ) -> GroupJoin<'a, Left, Right, LeftDur, RightDur, Out>
+  where
+    LeftDurF: Fn(Left) -> Observable<'a, LeftDur> + Send + Sync + 'a,
+    RightDurF: Fn(Right) -> Observable<'a, RightDur> + Send + Sync + 'a,
+    ResultF: Fn(Left, Observable<'a, Right>) -> Out + Send + Sync + 'a,
+  {
+    GroupJoin {
+      right,
+      left_duration_f: FunctionWrapper::new(left_duration_selector),
+      right_duration_f: FunctionWrapper::new(right_duration_selector),
+      result_f: FunctionWrapper::new(move |(l, r)| result_selector(l, r)),
+    }
+  }
+  pub fn execute(&self, source: Observable<'a, Left>) -> Observable<'a, Out> {
+    let right = self.right.clone();
+    let left_duration_f = self.left_duration_f.clone();
+    let right_duration_f = self.right_duration_f.clone();
+    let result_f = self.result_f.clone();
+
+    Observable::create(move |s| {
+      let state = Arc::new(RwLock::new(GroupJoinState {
+        windows: BTreeMap::new(),
+        right: BTreeMap::new(),
+        left_serial: 0,
+        right_serial: 0,
+      }));
+      let sctl = StreamController::new(s);
+
+      let take_windows = {
+        let state = Arc::clone(&state);
+        move || std::mem::take(&mut state.write().unwrap().windows)
+      };
+
+      // each left item gets a window of the right items whose durations
+      // overlap its own.
+      let obs_left = {
+        let left_duration_f = left_duration_f.clone();
+        let result_f = result_f.clone();
+        let state = Arc::clone(&state);
+        let take_windows_next = take_windows.clone();
+        let take_windows_error = take_windows.clone();
+        let take_windows_complete = take_windows.clone();
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x: Left| {
+            let sbj = subjects::Subject::<Right>::new();
+            let (id, rights) = {
+              let mut state = state.write().unwrap();
+              let id = state.left_serial;
+              state.left_serial += 1;
+              state.windows.insert(id, sbj.clone());
+              (
+                id,
+                state.right.values().cloned().collect::<Vec<_>>(),
+              )
+            };
+
+            sctl_next.sink_next(result_f.call((x.clone(), sbj.observable())));
+            rights.into_iter().for_each(|r| sbj.next(r));
+
+            let expire = {
+              let state = Arc::clone(&state);
+              let sctl = sctl_next.clone();
+              move |serial| {
+                sctl.upstream_abort_observe(&serial);
+                let sbj = state.write().unwrap().windows.remove(&id);
+                if let Some(sbj) = sbj {
+                  sbj.complete();
+                }
+              }
+            };
+            let expire_next = expire.clone();
+            let take_windows = take_windows_next.clone();
+            let sctl_duration_error = sctl_next.clone();
+            left_duration_f
+              .call(x)
+              .inner_subscribe(sctl_next.new_observer(
+                move |serial, _| expire_next(serial),
+                move |_, e| {
+                  take_windows().values().for_each(|sbj| sbj.error(e.clone()));
+                  sctl_duration_error.sink_error(e);
+                },
+                expire,
+              ));
+          },
+          move |_, e| {
+            take_windows_error()
+              .values()
+              .for_each(|sbj| sbj.error(e.clone()));
+            sctl_error.sink_error(e);
+          },
+          move |_| {
+            take_windows_complete()
+              .values()
+              .for_each(|sbj| sbj.complete());
+            sctl_complete.sink_complete_force();
+          },
+        )
+      };
+
+      let obs_right = {
+        let right_duration_f = right_duration_f.clone();
+        let state = Arc::clone(&state);
+        let take_windows_next = take_windows.clone();
+        let take_windows_error = take_windows.clone();
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x: Right| {
+            let (id, windows) = {
+              let mut state = state.write().unwrap();
+              let id = state.right_serial;
+              state.right_serial += 1;
+              state.right.insert(id, x.clone());
+              (
+                id,
+                state.windows.values().cloned().collect::<Vec<_>>(),
+              )
+            };
+
+            let expire = {
+              let state = Arc::clone(&state);
+              let sctl = sctl_next.clone();
+              move |serial| {
+                sctl.upstream_abort_observe(&serial);
+                state.write().unwrap().right.remove(&id);
+              }
+            };
+            let expire_next = expire.clone();
+            let take_windows = take_windows_next.clone();
+            let sctl_duration_error = sctl_next.clone();
+            right_duration_f.call(x.clone()).inner_subscribe(
+              sctl_next.new_observer(
+                move |serial, _| expire_next(serial),
+                move |_, e| {
+                  take_windows().values().for_each(|sbj| sbj.error(e.clone()));
+                  sctl_duration_error.sink_error(e);
+                },
+                expire,
+              ),
+            );
+
+            windows.iter().for_each(|sbj| sbj.next(x.clone()));
+          },
+          move |_, e| {
+            take_windows_error()
+              .values()
+              .for_each(|sbj| sbj.error(e.clone()));
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+          },
+        )
+      };
+
+      source.inner_subscribe(obs_left);
+      right.inner_subscribe(obs_right);
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn group_join<
+    Right,
+    LeftDur,
+    RightDur,
+    Out,
+    LeftDurF,
+    RightDurF,
+    ResultF,
+  >(
+    &self,
+    right: Observable<'a, Right>,
+    left_duration_selector: LeftDurF,
+    right_duration_selector: RightDurF,
+    result_selector: ResultF,
+  ) -> Observable<'a, Out>
+  where
+    Right: Clone + Send + Sync + 'a,
+    LeftDur: Clone + Send + Sync + 'a,
+    RightDur: Clone + Send + Sync + 'a,
+    Out: Clone + Send + Sync + 'a,
+    LeftDurF: Fn(Item) -> Observable<'a, LeftDur> + Send + Sync + 'a,
+    RightDurF: Fn(Right) -> Observable<'a, RightDur> + Send + Sync + 'a,
+    ResultF: Fn(Item, Observable<'a, Right>) -> Out + Send + Sync + 'a,
+  {
+    GroupJoin::new(
+      right,
+      left_duration_selector,
+      right_duration_selector,
+      result_selector,
+    )
+    .execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let left = subjects::Subject::<&str>::new();
+    let right = subjects::Subject::<i32>::new();
+    let left_closes =
+      [subjects::Subject::<()>::new(), subjects::Subject::new()];
+    let right_closes = subjects::Subject::<()>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      let left_closes = left_closes.clone();
+      let right_closes = right_closes.clone();
+      left
+        .observable()
+        .group_join(
+          right.observable(),
+          move |l| left_closes[if l == "a" { 0 } else { 1 }].observable(),
+          move |_| right_closes.observable(),
+          |l, rs| (l, rs),
+        )
+        .subscribe(
+          move |(l, rs)| {
+            let log = Arc::clone(&log);
+            let items = Arc::new(RwLock::new(Vec::new()));
+            let items_complete = Arc::clone(&items);
+            rs.subscribe(
+              move |r| items.write().unwrap().push(r),
+              junk_error!(),
+              move || {
+                log.write().unwrap().push((
+                  l,
+                  items_complete.read().unwrap().clone(),
+                ))
+              },
+            );
+          },
+          print_error!(),
+          print_complete!(),
+        );
+    }
+
+    right.next(1);
+    left.next("a");
+    right.next(2);
+    left.next("b");
+    left_closes[0].next(());
+    right_closes.next(());
+    right.next(3);
+    left.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![("a", vec![1, 2]), ("b", vec![1, 2, 3])]
+    );
+  }
+}
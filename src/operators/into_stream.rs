@@ -0,0 +1,210 @@
+use crate::prelude::*;
+use futures_core::Stream;
+use std::{
+  collections::VecDeque,
+  pin::Pin,
+  sync::{Arc, RwLock},
+  task::{Context, Poll, Waker},
+};
+
+struct ObservableStreamState<Item> {
+  buffer: VecDeque<Item>,
+  error: Option<RxError>,
+  done: bool,
+  waker: Option<Waker>,
+}
+
+pub struct ObservableStream<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  source: Option<Observable<'a, Item>>,
+  state: Arc<RwLock<ObservableStreamState<Item>>>,
+  subscription: Option<Subscription<'a>>,
+}
+
+impl<'a, Item> ObservableStream<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  fn start(&mut self, source: Observable<'a, Item>) {
+    fn wake<Item>(state: &mut ObservableStreamState<Item>) {
+      if let Some(w) = state.waker.take() {
+        w.wake();
+      }
+    }
+
+    let state_next = Arc::clone(&self.state);
+    let state_error = Arc::clone(&self.state);
+    let state_complete = Arc::clone(&self.state);
+    self.subscription = Some(source.subscribe(
+      move |x| {
+        let mut state = state_next.write().unwrap();
+        state.buffer.push_back(x);
+        wake(&mut state);
+      },
+      move |e| {
+        let mut state = state_error.write().unwrap();
+        state.error = Some(e);
+        state.done = true;
+        wake(&mut state);
+      },
+      move || {
+        let mut state = state_complete.write().unwrap();
+        state.done = true;
+        wake(&mut state);
+      },
+    ));
+  }
+}
+
+impl<'a, Item> Stream for ObservableStream<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  type Item = Result<Item, RxError>;
+
+  fn poll_next(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Option<Self::Item>> {
+    let this = self.get_mut();
+
+    // subscribe lazily, on the first poll.
+    if let Some(source) = this.source.take() {
+      this.start(source);
+    }
+
+    let mut state = this.state.write().unwrap();
+    if let Some(x) = state.buffer.pop_front() {
+      Poll::Ready(Some(Ok(x)))
+    } else if let Some(e) = state.error.take() {
+      Poll::Ready(Some(Err(e)))
+    } else if state.done {
+      Poll::Ready(None)
+    } else {
+      state.waker = Some(cx.waker().clone());
+      Poll::Pending
+    }
+  }
+}
+
+impl<'a, Item> Drop for ObservableStream<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  fn drop(&mut self) {
+    if let Some(subscription) = self.subscription.take() {
+      subscription.unsubscribe();
+    }
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn into_stream(self) -> ObservableStream<'a, Item> {
+    ObservableStream {
+      source: Some(self),
+      state: Arc::new(RwLock::new(ObservableStreamState {
+        buffer: VecDeque::new(),
+        error: None,
+        done: false,
+        waker: None,
+      })),
+      subscription: None,
+    }
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use futures_core::Stream;
+  use std::{
+    future,
+    pin::Pin,
+    sync::{Arc, RwLock},
+    task::Poll,
+    time,
+  };
+
+  async fn next<S>(s: &mut S) -> Option<S::Item>
+  where
+    S: Stream + Unpin,
+  {
+    future::poll_fn(|cx| Pin::new(&mut *s).poll_next(cx)).await
+  }
+
+  #[tokio::test]
+  async fn basic() {
+    let mut s = observables::from_iter(0..5).into_stream();
+    let mut items = Vec::new();
+    while let Some(x) = next(&mut s).await {
+      items.push(x.unwrap());
+    }
+    assert_eq!(items, vec![0, 1, 2, 3, 4]);
+  }
+
+  #[tokio::test]
+  async fn thread() {
+    let mut s = observables::interval(
+      time::Duration::from_millis(50),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(3)
+    .into_stream();
+    let mut items = Vec::new();
+    while let Some(x) = next(&mut s).await {
+      items.push(x.unwrap());
+    }
+    assert_eq!(items, vec![0, 1, 2]);
+  }
+
+  #[tokio::test]
+  async fn error() {
+    let mut s = observables::just(1)
+      .concat(&[observables::error(RxError::from_error("ERR!"))])
+      .into_stream();
+    assert_eq!(next(&mut s).await.unwrap().unwrap(), 1);
+    match next(&mut s).await {
+      Some(Err(e)) => assert_eq!(e.downcast_ref::<&str>(), Some(&"ERR!")),
+      _ => panic!(),
+    }
+    assert!(next(&mut s).await.is_none());
+  }
+
+  #[tokio::test]
+  async fn lazy_and_drop() {
+    let observer = Arc::new(RwLock::new(None));
+    let unsubscribed = Arc::new(RwLock::new(false));
+    let mut s = {
+      let observer = Arc::clone(&observer);
+      let unsubscribed = Arc::clone(&unsubscribed);
+      Observable::create(move |s| {
+        let unsubscribed = Arc::clone(&unsubscribed);
+        s.set_on_unsubscribe(move || *unsubscribed.write().unwrap() = true);
+        *observer.write().unwrap() = Some(s);
+      })
+      .into_stream()
+    };
+    assert!(observer.read().unwrap().is_none());
+
+    let pending = future::poll_fn(|cx| {
+      Poll::Ready(Pin::new(&mut s).poll_next(cx).is_pending())
+    })
+    .await;
+    assert!(pending);
+
+    let o = observer.read().unwrap().clone().unwrap();
+    o.next(1);
+    o.next(2);
+    assert_eq!(next(&mut s).await.unwrap().unwrap(), 1);
+    assert_eq!(next(&mut s).await.unwrap().unwrap(), 2);
+
+    drop(s);
+    assert!(*unsubscribed.read().unwrap());
+    assert!(!o.is_subscribed());
+  }
+}
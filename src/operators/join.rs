@@ -0,0 +1,306 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::{
+  collections::BTreeMap,
+  sync::{Arc, RwLock},
+};
+
+struct JoinState<Left, Right> {
+  left: BTreeMap<usize, Left>,
+  right: BTreeMap<usize, Right>,
+  left_serial: usize,
+  right_serial: usize,
+  left_completed: bool,
+  right_completed: bool,
+}
+
+#[derive(Clone)]
+pub struct Join<'a, Left, Right, LeftDur, RightDur, Out>
+where
+  Left: Clone + Send + Sync,
+  Right: Clone + Send + Sync,
+  LeftDur: Clone + Send + Sync,
+  RightDur: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  right: Observable<'a, Right>,
+  left_duration_f: FunctionWrapper<'a, Left, Observable<'a, LeftDur>>,
+  right_duration_f: FunctionWrapper<'a, Right, Observable<'a, RightDur>>,
+  result_f: FunctionWrapper<'a, (Left, Right), Out>,
+}
+
+impl<'a, Left, Right, LeftDur, RightDur, Out>
+  Join<'a, Left, Right, LeftDur, RightDur, Out>
+where
+  Left: Clone + Send + Sync + 'a,
+  Right: Clone + Send + Sync + 'a,
+  LeftDur: Clone + Send + Sync + 'a,
+  RightDur: Clone + Send + Sync + 'a,
+  Out: Clone + Send + Sync + 'a,
+{
+  pub fn new<LeftDurF, RightDurF, ResultF>(
+    right: Observable<'a, Right>,
+    left_duration_selector: LeftDurF,
+    right_duration_selector: RightDurF,
+    result_selector: ResultF,
+  ) -> Join<'a, Left, Right, LeftDur, RightDur, Out>
+  where
+    LeftDurF: Fn(Left) -> Observable<'a, LeftDur> + Send + Sync + 'a,
+    RightDurF: Fn(Right) -> Observable<'a, RightDur> + Send + Sync + 'a,
+    ResultF: Fn(Left, Right) -> Out + Send + Sync + 'a,
+  {
+    Join {
+      right,
+      left_duration_f: FunctionWrapper::new(left_duration_selector),
+      right_duration_f: FunctionWrapper::new(right_duration_selector),
+      result_f: FunctionWrapper::new(move |(l, r)| result_selector(l, r)),
+    }
+  }
+  pub fn execute(&self, source: Observable<'a, Left>) -> Observable<'a, Out> {
+    let right = self.right.clone();
+    let left_duration_f = self.left_duration_f.clone();
+    let right_duration_f = self.right_duration_f.clone();
+    let result_f = self.result_f.clone();
+
+    Observable::create(move |s| {
+      let state = Arc::new(RwLock::new(JoinState {
+        left: BTreeMap::new(),
+        right: BTreeMap::new(),
+        left_serial: 0,
+        right_serial: 0,
+        left_completed: false,
+        right_completed: false,
+      }));
+      let sctl = StreamController::new(s);
+
+      // an item stays in its window until its duration observable emits or
+      // completes. the result completes once a completed side has no open
+      // windows left.
+      let obs_left = {
+        let left_duration_f = left_duration_f.clone();
+        let result_f = result_f.clone();
+        let state_next = Arc::clone(&state);
+        let state_complete = Arc::clone(&state);
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x: Left| {
+            let (id, rights) = {
+              let mut state = state_next.write().unwrap();
+              let id = state.left_serial;
+              state.left_serial += 1;
+              state.left.insert(id, x.clone());
+              (
+                id,
+                state.right.values().cloned().collect::<Vec<_>>(),
+              )
+            };
+
+            let expire = {
+              let state = Arc::clone(&state_next);
+              let sctl = sctl_next.clone();
+              move |serial| {
+                sctl.upstream_abort_observe(&serial);
+                let done = {
+                  let mut state = state.write().unwrap();
+                  state.left.remove(&id).is_some()
+                    && state.left.is_empty()
+                    && state.left_completed
+                };
+                if done {
+                  sctl.sink_complete_force();
+                }
+              }
+            };
+            let expire_next = expire.clone();
+            let sctl_duration_error = sctl_next.clone();
+            left_duration_f.call(x.clone()).inner_subscribe(
+              sctl_next.new_observer(
+                move |serial, _| expire_next(serial),
+                move |_, e| {
+                  sctl_duration_error.sink_error(e);
+                },
+                expire,
+              ),
+            );
+
+            rights.into_iter().for_each(|r| {
+              sctl_next.sink_next(result_f.call((x.clone(), r)));
+            });
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+            let done = {
+              let mut state = state_complete.write().unwrap();
+              state.left_completed = true;
+              state.right_completed || state.left.is_empty()
+            };
+            if done {
+              sctl_complete.sink_complete_force();
+            }
+          },
+        )
+      };
+
+      let obs_right = {
+        let right_duration_f = right_duration_f.clone();
+        let result_f = result_f.clone();
+        let state_next = Arc::clone(&state);
+        let state_complete = Arc::clone(&state);
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x: Right| {
+            let (id, lefts) = {
+              let mut state = state_next.write().unwrap();
+              let id = state.right_serial;
+              state.right_serial += 1;
+              state.right.insert(id, x.clone());
+              (
+                id,
+                state.left.values().cloned().collect::<Vec<_>>(),
+              )
+            };
+
+            let expire = {
+              let state = Arc::clone(&state_next);
+              let sctl = sctl_next.clone();
+              move |serial| {
+                sctl.upstream_abort_observe(&serial);
+                let done = {
+                  let mut state = state.write().unwrap();
+                  state.right.remove(&id).is_some()
+                    && state.right.is_empty()
+                    && state.right_completed
+                };
+                if done {
+                  sctl.sink_complete_force();
+                }
+              }
+            };
+            let expire_next = expire.clone();
+            let sctl_duration_error = sctl_next.clone();
+            right_duration_f.call(x.clone()).inner_subscribe(
+              sctl_next.new_observer(
+                move |serial, _| expire_next(serial),
+                move |_, e| {
+                  sctl_duration_error.sink_error(e);
+                },
+                expire,
+              ),
+            );
+
+            lefts.into_iter().for_each(|l| {
+              sctl_next.sink_next(result_f.call((l, x.clone())));
+            });
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+            let done = {
+              let mut state = state_complete.write().unwrap();
+              state.right_completed = true;
+              state.left_completed || state.right.is_empty()
+            };
+            if done {
+              sctl_complete.sink_complete_force();
+            }
+          },
+        )
+      };
+
+      source.inner_subscribe(obs_left);
+      right.inner_subscribe(obs_right);
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn join<Right, LeftDur, RightDur, Out, LeftDurF, RightDurF, ResultF>(
+    &self,
+    right: Observable<'a, Right>,
+    left_duration_selector: LeftDurF,
+    right_duration_selector: RightDurF,
+    result_selector: ResultF,
+  ) -> Observable<'a, Out>
+  where
+    Right: Clone + Send + Sync + 'a,
+    LeftDur: Clone + Send + Sync + 'a,
+    RightDur: Clone + Send + Sync + 'a,
+    Out: Clone + Send + Sync + 'a,
+    LeftDurF: Fn(Item) -> Observable<'a, LeftDur> + Send + Sync + 'a,
+    RightDurF: Fn(Right) -> Observable<'a, RightDur> + Send + Sync + 'a,
+    ResultF: Fn(Item, Right) -> Out + Send + Sync + 'a,
+  {
+    Join::new(
+      right,
+      left_duration_selector,
+      right_duration_selector,
+      result_selector,
+    )
+    .execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let left = subjects::Subject::<&str>::new();
+    let right = subjects::Subject::<i32>::new();
+    let left_closes = subjects::Subject::<()>::new();
+    let right_closes = subjects::Subject::<()>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let completed = Arc::new(RwLock::new(false));
+
+    {
+      let log = Arc::clone(&log);
+      let completed = Arc::clone(&completed);
+      let left_closes = left_closes.clone();
+      let right_closes = right_closes.clone();
+      left
+        .observable()
+        .join(
+          right.observable(),
+          move |_| left_closes.observable(),
+          move |_| right_closes.observable(),
+          |l, r| format!("{}{}", l, r),
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          move || *completed.write().unwrap() = true,
+        );
+    }
+
+    left.next("a");
+    right.next(1);
+    left.next("b");
+    // every window is closed here.
+    left_closes.next(());
+    right_closes.next(());
+    right.next(2);
+    left.next("c");
+    left.complete();
+    assert!(!*completed.read().unwrap());
+    left_closes.complete();
+    assert!(*completed.read().unwrap());
+    assert_eq!(
+      *log.read().unwrap(),
+      vec!["a1".to_owned(), "b1".to_owned(), "c2".to_owned()]
+    );
+  }
+}
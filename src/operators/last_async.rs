@@ -0,0 +1,48 @@
+use crate::internals::awaitable::*;
+use crate::prelude::*;
+
+pub type LastAsync<'a, Item> = Awaitable<'a, Item>;
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn last_async(&self) -> LastAsync<'a, Item> {
+    let source = self.last();
+    Awaitable::new(move |sink| {
+      let sink_error = sink.clone();
+      let sink_complete = sink.clone();
+      source.subscribe(
+        move |x| sink.resolve(Ok(x)),
+        move |e| sink_error.resolve(Err(e)),
+        move || sink_complete.resolve(Err(no_elements_error())),
+      )
+    })
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+
+  #[tokio::test]
+  async fn basic() {
+    assert_eq!(
+      observables::from_iter(0..5).last_async().await.unwrap(),
+      4
+    );
+    assert!(observables::empty::<i32>().last_async().await.is_err());
+  }
+
+  #[tokio::test]
+  async fn error() {
+    let r = observables::just(1)
+      .concat(&[observables::error(RxError::from_error("ERR!"))])
+      .last_async()
+      .await;
+    assert_eq!(
+      r.unwrap_err().downcast_ref::<&str>(),
+      Some(&"ERR!")
+    );
+  }
+}
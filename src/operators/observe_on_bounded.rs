@@ -0,0 +1,465 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use scheduler::IScheduler;
+use std::{
+  collections::VecDeque,
+  marker::PhantomData,
+  sync::{Arc, Condvar, Mutex, MutexGuard},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowStrategy {
+  Block,
+  DropOldest,
+  DropNewest,
+  Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BufferOverflowError {
+  pub capacity: usize,
+}
+
+struct BoundedState<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  queue: VecDeque<Material<Item>>,
+  draining: bool,
+  started: bool,
+  delivering: bool,
+  terminated: bool,
+}
+
+// the queue counts as full only while a drain task is pending or running, so
+// a capacity of 0 accepts an item only when the observer is idle.
+impl<Item> BoundedState<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  fn is_full(&self, capacity: usize) -> bool {
+    self.draining && self.queue.len() >= capacity
+  }
+
+  // a drain task has been posted but has not started yet.
+  fn is_deferred(&self) -> bool {
+    self.draining && !self.started && !self.delivering
+  }
+}
+
+fn deliver<Item>(sctl: &StreamController<Item>, m: Material<Item>)
+where
+  Item: Clone + Send + Sync,
+{
+  match m {
+    Material::Next(x) => sctl.sink_next(x),
+    Material::Error(e) => sctl.sink_error(e),
+    Material::Complete => sctl.sink_complete_force(),
+  }
+}
+
+#[derive(Clone)]
+pub struct ObserveOnBounded<'a, Scheduler, Item>
+where
+  Scheduler: IScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  scheduler_ctor: FunctionWrapper<'a, (), Scheduler>,
+  capacity: usize,
+  strategy: OverflowStrategy,
+  _item: PhantomData<Item>,
+  _lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a, Scheduler, Item> ObserveOnBounded<'a, Scheduler, Item>
+where
+  Scheduler: IScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  pub fn new<SchedulerCreator>(
+    scheduler_ctor: SchedulerCreator,
+    capacity: usize,
+    strategy: OverflowStrategy,
+  ) -> ObserveOnBounded<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    ObserveOnBounded {
+      scheduler_ctor: FunctionWrapper::new(move |_| scheduler_ctor()),
+      capacity,
+      strategy,
+      _item: PhantomData,
+      _lifetime: PhantomData,
+    }
+  }
+
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    let scheduler_ctor = self.scheduler_ctor.clone();
+    let capacity = self.capacity;
+    let strategy = self.strategy;
+    Observable::create(move |s| {
+      let scheduler = scheduler_ctor.call(());
+
+      let sctl = StreamController::new(s);
+      let state = Arc::new((
+        Mutex::new(BoundedState {
+          queue: VecDeque::new(),
+          draining: false,
+          started: false,
+          delivering: false,
+          terminated: false,
+        }),
+        Condvar::new(),
+      ));
+
+      // runs on the scheduler until the queue is empty. callers post it
+      // after releasing the lock, so an inline scheduler can't deadlock.
+      let drain = {
+        let sctl = sctl.clone();
+        let state = Arc::clone(&state);
+        move || loop {
+          let m = {
+            let (lock, cvar) = &*state;
+            let mut st = cvar
+              .wait_while(lock.lock().unwrap(), |st| st.delivering)
+              .unwrap();
+            st.started = true;
+            let m = st.queue.pop_front();
+            match &m {
+              Some(_) => st.delivering = true,
+              None => {
+                st.draining = false;
+                st.started = false;
+              }
+            }
+            cvar.notify_all();
+            match m {
+              Some(m) => m,
+              None => return,
+            }
+          };
+          deliver(&sctl, m);
+          let (lock, cvar) = &*state;
+          lock.lock().unwrap().delivering = false;
+          cvar.notify_all();
+        }
+      };
+      let enqueue = {
+        let scheduler = scheduler.clone();
+        move |mut st: MutexGuard<BoundedState<Item>>, m: Material<Item>| {
+          st.queue.push_back(m);
+          if st.draining {
+            return;
+          }
+          st.draining = true;
+          drop(st);
+          scheduler.post(drain.clone());
+        }
+      };
+
+      {
+        let state = Arc::clone(&state);
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+          let (lock, cvar) = &*state;
+          lock.lock().unwrap().terminated = true;
+          cvar.notify_all();
+        });
+      }
+
+      // only a scheduler that runs posted work on this thread after we return
+      // needs a blocked producer to deliver on its behalf.
+      let defers = scheduler.defers_on_current_thread();
+
+      let sctl_next = sctl.clone();
+      let state_next = Arc::clone(&state);
+      let state_error = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+      let enqueue_next = enqueue.clone();
+      let enqueue_error = enqueue.clone();
+      let enqueue_complete = enqueue;
+
+      source.inner_subscribe(sctl.new_observer(
+        move |serial, x: Item| {
+          let (lock, cvar) = &*state_next;
+          let mut st = lock.lock().unwrap();
+          if st.terminated {
+            return;
+          }
+          if st.is_full(capacity) {
+            match strategy {
+              OverflowStrategy::Block => {
+                // a scheduler that defers posted work until the producer
+                // returns would never make room, so the blocked producer
+                // delivers the queued items itself.
+                while !st.terminated && st.is_full(capacity) {
+                  if !(defers && st.is_deferred()) {
+                    st = cvar.wait(st).unwrap();
+                    continue;
+                  }
+                  st.delivering = true;
+                  let m = st.queue.pop_front();
+                  drop(st);
+                  // with nothing queued, `x` itself is next in line.
+                  let inline = m.is_none();
+                  deliver(
+                    &sctl_next,
+                    m.unwrap_or_else(|| Material::Next(x.clone())),
+                  );
+                  st = lock.lock().unwrap();
+                  st.delivering = false;
+                  cvar.notify_all();
+                  if inline {
+                    return;
+                  }
+                }
+                if st.terminated {
+                  return;
+                }
+              }
+              OverflowStrategy::DropOldest => {
+                if st.queue.pop_front().is_none() {
+                  return;
+                }
+              }
+              OverflowStrategy::DropNewest => return,
+              OverflowStrategy::Error => {
+                st.terminated = true;
+                enqueue_next(
+                  st,
+                  Material::Error(RxError::from_error(
+                    BufferOverflowError { capacity },
+                  )),
+                );
+                sctl_next.upstream_abort_observe(&serial);
+                return;
+              }
+            }
+          }
+          enqueue_next(st, Material::Next(x));
+        },
+        move |_, e| {
+          let mut st = state_error.0.lock().unwrap();
+          if st.terminated {
+            return;
+          }
+          st.terminated = true;
+          enqueue_error(st, Material::Error(e));
+        },
+        move |_| {
+          let mut st = state_complete.0.lock().unwrap();
+          if st.terminated {
+            return;
+          }
+          st.terminated = true;
+          enqueue_complete(st, Material::Complete);
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn observe_on_bounded<Scheduler, SchedulerCreator>(
+    &self,
+    scheduler_ctor: SchedulerCreator,
+    capacity: usize,
+    strategy: OverflowStrategy,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    ObserveOnBounded::new(scheduler_ctor, capacity, strategy)
+      .execute(self.clone())
+  }
+
+  // errors once more than `capacity` items wait for the observer.
+  pub fn on_backpressure_buffer<Scheduler, SchedulerCreator>(
+    &self,
+    scheduler_ctor: SchedulerCreator,
+    capacity: usize,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    self.observe_on_bounded(
+      scheduler_ctor,
+      capacity,
+      OverflowStrategy::Error,
+    )
+  }
+
+  // drops items that arrive while the observer is busy.
+  pub fn on_backpressure_drop<Scheduler, SchedulerCreator>(
+    &self,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    self.observe_on_bounded(
+      scheduler_ctor,
+      0,
+      OverflowStrategy::DropNewest,
+    )
+  }
+
+  // keeps only the latest item that arrives while the observer is busy.
+  pub fn on_backpressure_latest<Scheduler, SchedulerCreator>(
+    &self,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    self.observe_on_bounded(
+      scheduler_ctor,
+      1,
+      OverflowStrategy::DropOldest,
+    )
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use operators::OverflowStrategy;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  type Log = Arc<RwLock<(Vec<i64>, Option<RxError>, bool)>>;
+
+  fn subscribe<'a>(o: Observable<'a, i64>) -> Log {
+    let log = Arc::new(RwLock::new((Vec::new(), None, false)));
+    {
+      let log_next = Arc::clone(&log);
+      let log_error = Arc::clone(&log);
+      let log_complete = Arc::clone(&log);
+      o.subscribe(
+        move |x| log_next.write().unwrap().0.push(x),
+        move |e| log_error.write().unwrap().1 = Some(e),
+        move || log_complete.write().unwrap().2 = true,
+      );
+    }
+    log
+  }
+
+  // the test scheduler runs the drain only when time is advanced, so the whole
+  // source overflows the buffer first.
+  fn run(
+    f: impl Fn(
+      Observable<'static, i64>,
+      Box<dyn Fn() -> schedulers::TestScheduler<'static> + Send + Sync>,
+    ) -> Observable<'static, i64>,
+  ) -> (Vec<i64>, Option<RxError>, bool) {
+    let ts = schedulers::TestScheduler::new();
+    let log = subscribe(f(
+      observables::range(0, 20),
+      Box::new(ts.scheduler_ctor()),
+    ));
+    ts.advance_by(time::Duration::ZERO);
+    let log = log.read().unwrap();
+    (log.0.clone(), log.1.clone(), log.2)
+  }
+
+  #[test]
+  fn block() {
+    let ts = schedulers::TestScheduler::new();
+    let log = subscribe(
+      observables::range(0, 5).observe_on_bounded(
+        ts.scheduler_ctor(),
+        1,
+        OverflowStrategy::Block,
+      ),
+    );
+    // the blocked producer delivers for the deferred scheduler.
+    assert_eq!(log.read().unwrap().0, vec![0, 1, 2, 3]);
+    assert!(!log.read().unwrap().2);
+
+    ts.advance_by(time::Duration::ZERO);
+    assert_eq!(
+      log.read().unwrap().0,
+      vec![0, 1, 2, 3, 4]
+    );
+    assert!(log.read().unwrap().2);
+  }
+
+  #[test]
+  fn block_thread() {
+    let producer = thread::current().id();
+    let items = observables::range(0, 200)
+      .observe_on_bounded(
+        schedulers::new_thread_scheduler(),
+        2,
+        OverflowStrategy::Block,
+      )
+      .map(|x| {
+        thread::sleep(time::Duration::from_micros(100));
+        (x, thread::current().id())
+      })
+      .to_blocking_iter()
+      .collect::<Result<Vec<_>, _>>()
+      .unwrap();
+
+    assert_eq!(
+      items.iter().map(|(x, _)| *x).collect::<Vec<_>>(),
+      (0..200).collect::<Vec<_>>()
+    );
+    assert!(items.iter().all(|(_, id)| *id != producer));
+  }
+
+  #[test]
+  fn drop_newest() {
+    let (items, error, completed) = run(|o, ctor| {
+      o.observe_on_bounded(ctor, 2, OverflowStrategy::DropNewest)
+    });
+    assert_eq!(items, vec![0, 1]);
+    assert!(error.is_none());
+    assert!(completed);
+  }
+
+  #[test]
+  fn drop_oldest() {
+    let (items, error, completed) = run(|o, ctor| {
+      o.observe_on_bounded(ctor, 2, OverflowStrategy::DropOldest)
+    });
+    assert_eq!(items, vec![18, 19]);
+    assert!(error.is_none());
+    assert!(completed);
+  }
+
+  #[test]
+  fn error() {
+    let (items, error, completed) =
+      run(|o, ctor| o.on_backpressure_buffer(ctor, 2));
+    assert_eq!(items, vec![0, 1]);
+    assert_eq!(
+      error
+        .unwrap()
+        .downcast_ref::<operators::BufferOverflowError>(),
+      Some(&operators::BufferOverflowError { capacity: 2 })
+    );
+    assert!(!completed);
+  }
+
+  #[test]
+  fn drop_and_latest() {
+    let (items, _, completed) = run(|o, ctor| o.on_backpressure_drop(ctor));
+    assert_eq!(items, vec![0]);
+    assert!(completed);
+
+    let (items, _, completed) = run(|o, ctor| o.on_backpressure_latest(ctor));
+    assert_eq!(items, vec![19]);
+    assert!(completed);
+  }
+}
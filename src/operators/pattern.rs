@@ -0,0 +1,129 @@
+use crate::internals::function_wrapper::*;
+use crate::prelude::*;
+use std::{any::Any, sync::Arc};
+
+pub(crate) type AnyItem = Arc<dyn Any + Send + Sync + 'static>;
+
+fn to_any<'a, Item>(
+  o: &Observable<'a, Item>,
+) -> (usize, Observable<'a, AnyItem>)
+where
+  Item: Clone + Send + Sync + 'static,
+{
+  (
+    o.id(),
+    o.map(|x| Arc::new(x) as AnyItem),
+  )
+}
+
+fn from_any<Item>(x: &AnyItem) -> Item
+where
+  Item: Clone + Send + Sync + 'static,
+{
+  x.downcast_ref::<Item>().unwrap().clone()
+}
+
+// a set of observables whose items are joined by arrival order.
+// each source is kept with the id of the original observable, so that plans
+// sharing an observable share its queue in `observables::when`.
+#[derive(Clone)]
+pub struct Pattern<'a, Items>
+where
+  Items: Clone + Send + Sync,
+{
+  sources: Vec<(usize, Observable<'a, AnyItem>)>,
+  decode_f: FunctionWrapper<'a, Vec<AnyItem>, Items>,
+}
+
+#[derive(Clone)]
+pub struct Plan<'a, Out>
+where
+  Out: Clone + Send + Sync,
+{
+  pub(crate) sources: Vec<(usize, Observable<'a, AnyItem>)>,
+  pub(crate) selector_f: FunctionWrapper<'a, Vec<AnyItem>, Out>,
+}
+
+impl<'a, Items> Pattern<'a, Items>
+where
+  Items: Clone + Send + Sync + 'a,
+{
+  pub fn then<Out, F>(&self, f: F) -> Plan<'a, Out>
+  where
+    Out: Clone + Send + Sync + 'a,
+    F: Fn(Items) -> Out + Send + Sync + 'a,
+  {
+    let decode_f = self.decode_f.clone();
+    Plan {
+      sources: self.sources.clone(),
+      selector_f: FunctionWrapper::new(move |v| f(decode_f.call(v))),
+    }
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync + 'static,
+{
+  pub fn and<B>(&self, other: &Observable<'a, B>) -> Pattern<'a, (Item, B)>
+  where
+    B: Clone + Send + Sync + 'static,
+  {
+    Pattern {
+      sources: vec![to_any(self), to_any(other)],
+      decode_f: FunctionWrapper::new(|v: Vec<AnyItem>| {
+        (from_any(&v[0]), from_any(&v[1]))
+      }),
+    }
+  }
+
+  pub fn then<Out, F>(&self, f: F) -> Plan<'a, Out>
+  where
+    Out: Clone + Send + Sync + 'a,
+    F: Fn(Item) -> Out + Send + Sync + 'a,
+  {
+    Plan {
+      sources: vec![to_any(self)],
+      selector_f: FunctionWrapper::new(move |v: Vec<AnyItem>| {
+        f(from_any(&v[0]))
+      }),
+    }
+  }
+}
+
+macro_rules! pattern_and {
+  ($next: ident; $($t: ident),+) => {
+    impl<'a, $($t),+> Pattern<'a, ($($t),+)>
+    where
+      $($t: Clone + Send + Sync + 'static),+
+    {
+      pub fn and<$next>(
+        &self,
+        other: &Observable<'a, $next>,
+      ) -> Pattern<'a, ($($t),+, $next)>
+      where
+        $next: Clone + Send + Sync + 'static,
+      {
+        let mut sources = self.sources.clone();
+        sources.push(to_any(other));
+        let decode_f = self.decode_f.clone();
+        Pattern {
+          sources,
+          decode_f: FunctionWrapper::new(move |mut v: Vec<AnyItem>| {
+            let last = v.pop().unwrap();
+            #[allow(non_snake_case)]
+            let ($($t),+) = decode_f.call(v);
+            ($($t),+, from_any(&last))
+          }),
+        }
+      }
+    }
+  };
+}
+
+pattern_and!(C; A, B);
+pattern_and!(D; A, B, C);
+pattern_and!(E; A, B, C, D);
+pattern_and!(F; A, B, C, D, E);
+pattern_and!(G; A, B, C, D, E, F);
+pattern_and!(H; A, B, C, D, E, F, G);
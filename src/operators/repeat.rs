@@ -0,0 +1,138 @@
+use crate::internals::stream_controller::*;
+use crate::prelude::*;
+use scheduler::IScheduler;
+use std::marker::PhantomData;
+
+#[derive(Clone)]
+pub struct Repeat<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  count: usize,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Item> Repeat<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn new(count: usize) -> Repeat<Item> {
+    Repeat { count, _item: PhantomData }
+  }
+
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    let count = self.count;
+
+    Observable::<Item>::create(move |s| {
+      fn do_subscribe<'a, Item>(
+        n: usize,
+        max_repeat: usize,
+        source: Observable<'a, Item>,
+        sctl: StreamController<'a, Item>,
+        scheduler: schedulers::CurrentThreadScheduler<'a>,
+      ) where
+        Item: Clone + Send + Sync,
+      {
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        let source_complete = source.clone();
+        source.inner_subscribe(sctl.new_observer(
+          move |_, x: Item| {
+            sctl_next.sink_next(x);
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            if max_repeat == 0 || n < max_repeat {
+              sctl_complete.upstream_abort_observe(&serial);
+              let source = source_complete.clone();
+              let sctl = sctl_complete.clone();
+              let scheduler_next = scheduler.clone();
+              scheduler.post(move || {
+                do_subscribe(
+                  n + 1,
+                  max_repeat,
+                  source.clone(),
+                  sctl.clone(),
+                  scheduler_next.clone(),
+                );
+              });
+            } else {
+              sctl_complete.sink_complete(&serial);
+            }
+          },
+        ));
+      }
+
+      let sctl = StreamController::new(s);
+      let scheduler = schedulers::CurrentThreadScheduler::new();
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let source = source.clone();
+      let scheduler_subscribe = scheduler.clone();
+      scheduler.post(move || {
+        do_subscribe(
+          1,
+          count,
+          source.clone(),
+          sctl.clone(),
+          scheduler_subscribe.clone(),
+        );
+      });
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn repeat(&self, count: usize) -> Observable<'a, Item> {
+    Repeat::new(count).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    observables::from_iter(0..3).repeat(3).subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+
+    observables::from_iter(0..3).repeat(0).take(10).subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+  }
+
+  #[test]
+  fn many_times() {
+    let counter = Arc::new(RwLock::new(0));
+    let completed = Arc::new(RwLock::new(false));
+    {
+      let counter = Arc::clone(&counter);
+      let completed = Arc::clone(&completed);
+      observables::just(1).repeat(1_000_000).subscribe(
+        move |x| *counter.write().unwrap() += x,
+        junk_error!(),
+        move || *completed.write().unwrap() = true,
+      );
+    }
+    assert_eq!(*counter.read().unwrap(), 1_000_000);
+    assert!(*completed.read().unwrap());
+  }
+}
@@ -1,5 +1,6 @@
 use crate::internals::stream_controller::*;
 use crate::prelude::*;
+use scheduler::IScheduler;
 use std::marker::PhantomData;
 
 #[derive(Clone)]
@@ -28,6 +29,7 @@ where
         max_retry: usize,
         source: Observable<'a, Item>,
         sctl: StreamController<'a, Item>,
+        scheduler: schedulers::CurrentThreadScheduler<'a>,
       ) where
         Item: Clone + Send + Sync,
       {
@@ -42,12 +44,18 @@ where
           move |serial, e| {
             if max_retry == 0 || n < max_retry {
               sctl_error.upstream_abort_observe(&serial);
-              do_subscribe(
-                n + 1,
-                max_retry,
-                source_error.clone(),
-                sctl_error.clone(),
-              );
+              let source = source_error.clone();
+              let sctl = sctl_error.clone();
+              let scheduler_next = scheduler.clone();
+              scheduler.post(move || {
+                do_subscribe(
+                  n + 1,
+                  max_retry,
+                  source.clone(),
+                  sctl.clone(),
+                  scheduler_next.clone(),
+                );
+              });
             } else {
               sctl_error.sink_error(e);
             }
@@ -57,7 +65,25 @@ where
       }
 
       let sctl = StreamController::new(s);
-      do_subscribe(1, count, source.clone(), sctl.clone());
+      let scheduler = schedulers::CurrentThreadScheduler::new();
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let source = source.clone();
+      let scheduler_subscribe = scheduler.clone();
+      scheduler.post(move || {
+        do_subscribe(
+          1,
+          count,
+          source.clone(),
+          sctl.clone(),
+          scheduler_subscribe.clone(),
+        );
+      });
     })
   }
 }
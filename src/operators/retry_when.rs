@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 use crate::internals::function_wrapper::*;
 use crate::internals::stream_controller::*;
 use crate::prelude::*;
+use scheduler::IScheduler;
 
 #[derive(Clone)]
 pub struct RetryWhen<'a, Item>
@@ -35,6 +36,7 @@ where
         predicate: FunctionWrapper<'a, RxError, bool>,
         source: Observable<'a, Item>,
         sctl: StreamController<'a, Item>,
+        scheduler: schedulers::CurrentThreadScheduler<'a>,
       ) where
         Item: Clone + Send + Sync,
       {
@@ -49,11 +51,18 @@ where
           move |serial, e| {
             if predicate.call(e.clone()) {
               sctl_error.upstream_abort_observe(&serial);
-              do_subscribe(
-                predicate.clone(),
-                source_error.clone(),
-                sctl_error.clone(),
-              );
+              let predicate = predicate.clone();
+              let source = source_error.clone();
+              let sctl = sctl_error.clone();
+              let scheduler_next = scheduler.clone();
+              scheduler.post(move || {
+                do_subscribe(
+                  predicate.clone(),
+                  source.clone(),
+                  sctl.clone(),
+                  scheduler_next.clone(),
+                );
+              });
             } else {
               sctl_error.sink_error(e);
             }
@@ -63,7 +72,25 @@ where
       }
 
       let sctl = StreamController::new(s);
-      do_subscribe(f.clone(), source.clone(), sctl.clone());
+      let scheduler = schedulers::CurrentThreadScheduler::new();
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let f = f.clone();
+      let source = source.clone();
+      let scheduler_subscribe = scheduler.clone();
+      scheduler.post(move || {
+        do_subscribe(
+          f.clone(),
+          source.clone(),
+          sctl.clone(),
+          scheduler_subscribe.clone(),
+        );
+      });
     })
   }
 }
@@ -0,0 +1,209 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use scheduler::ITimedScheduler;
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct SampleTime<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  dur: Duration,
+  emit_last: bool,
+  scheduler_ctor: FunctionWrapper<'a, (), Scheduler>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Scheduler, Item> SampleTime<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+  Item: Clone + Send + Sync,
+{
+  pub fn new<SchedulerCreator>(
+    dur: Duration,
+    emit_last: bool,
+    scheduler_ctor: SchedulerCreator,
+  ) -> SampleTime<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    SampleTime {
+      dur,
+      emit_last,
+      scheduler_ctor: FunctionWrapper::new(move |_| scheduler_ctor()),
+      _item: PhantomData,
+    }
+  }
+
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    let dur = self.dur;
+    let emit_last = self.emit_last;
+    let scheduler_ctor = self.scheduler_ctor.clone();
+
+    Observable::<Item>::create(move |s| {
+      let value = Arc::new(RwLock::new(None::<Item>));
+      let sctl = StreamController::new(s);
+
+      let scheduler = scheduler_ctor.call(());
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+      let value_next = Arc::clone(&value);
+      let value_complete = Arc::clone(&value);
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          *value_next.write().unwrap() = Some(x);
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          let value = value_complete.write().unwrap().take();
+          if emit_last {
+            if let Some(value) = value {
+              sctl_complete.sink_next(value);
+            }
+          }
+          sctl_complete.sink_complete(&serial);
+        },
+      ));
+
+      // periods without a new item are skipped.
+      scheduler.schedule_periodic(dur, move || {
+        let value = value.write().unwrap().take();
+        if let Some(value) = value {
+          sctl_next.sink_next(value);
+        }
+      });
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn sample_time<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    SampleTime::new(dur, false, scheduler_ctor).execute(self.clone())
+  }
+
+  pub fn sample_time_emit_last<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    SampleTime::new(dur, true, scheduler_ctor).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .sample_time(
+          time::Duration::from_millis(100),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          junk_error!(),
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    ts.advance_by(time::Duration::from_millis(100));
+    ts.advance_by(time::Duration::from_millis(100));
+    sbj.next(2);
+    ts.advance_by(time::Duration::from_millis(100));
+    sbj.next(3);
+    sbj.complete();
+    assert_eq!(*log.read().unwrap(), vec![1, 2]);
+  }
+
+  #[test]
+  fn emit_last() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .sample_time_emit_last(
+          time::Duration::from_millis(100),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          junk_error!(),
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    ts.advance_by(time::Duration::from_millis(100));
+    sbj.next(1);
+    sbj.complete();
+    ts.advance_by(time::Duration::from_millis(100));
+    assert_eq!(*log.read().unwrap(), vec![0, 1]);
+  }
+
+  #[test]
+  fn thread() {
+    observables::interval(
+      time::Duration::from_millis(10),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(30)
+    .sample_time(
+      time::Duration::from_millis(100),
+      schedulers::new_thread_scheduler(),
+    )
+    .subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+    thread::sleep(time::Duration::from_millis(700));
+  }
+}
@@ -0,0 +1,122 @@
+use crate::internals::{serializer::*, stream_controller::*};
+use crate::prelude::*;
+use std::marker::PhantomData;
+
+#[derive(Clone)]
+pub struct Serialize<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Item> Serialize<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn new() -> Serialize<Item> {
+    Serialize { _item: PhantomData }
+  }
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    Observable::<Item>::create(move |s| {
+      let sctl = StreamController::new(s);
+
+      let serializer = {
+        let sctl = sctl.clone();
+        Serializer::new(move |m| match m {
+          Material::Next(x) => sctl.sink_next(x),
+          Material::Error(e) => sctl.sink_error(e),
+          Material::Complete => sctl.sink_complete_force(),
+        })
+      };
+      let serializer_next = serializer.clone();
+      let serializer_error = serializer.clone();
+      let serializer_complete = serializer.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| serializer_next.push(Material::Next(x)),
+        move |_, e| serializer_error.push(Material::Error(e)),
+        move |_| serializer_complete.push(Material::Complete),
+      ));
+    })
+  }
+}
+
+impl<Item> Default for Serialize<Item>
+where
+  Item: Clone + Send + Sync,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn serialize(&self) -> Observable<'a, Item> {
+    Serialize::new().execute(self.clone())
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{
+      atomic::{AtomicBool, Ordering},
+      Arc, RwLock,
+    },
+    thread, time,
+  };
+
+  #[test]
+  fn thread() {
+    let o = Observable::create(|s| {
+      let threads = (0..4)
+        .map(|n| {
+          let s = s.clone();
+          thread::spawn(move || {
+            (0..10).for_each(|x| s.next(n * 100 + x));
+          })
+        })
+        .collect::<Vec<_>>();
+      threads.into_iter().for_each(|th| {
+        th.join().ok();
+      });
+      s.complete();
+    });
+
+    let busy = Arc::new(AtomicBool::new(false));
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      o.serialize().subscribe(
+        move |x| {
+          assert!(!busy.swap(true, Ordering::SeqCst));
+          thread::sleep(time::Duration::from_millis(1));
+          log.write().unwrap().push(x);
+          busy.store(false, Ordering::SeqCst);
+        },
+        print_error!(),
+        print_complete!(),
+      );
+    }
+
+    let log = log.read().unwrap();
+    assert_eq!(log.len(), 40);
+    // each thread's items keep their order.
+    (0..4).for_each(|n| {
+      let items = log
+        .iter()
+        .filter(|x| **x / 100 == n)
+        .cloned()
+        .collect::<Vec<_>>();
+      assert_eq!(
+        items,
+        (0..10).map(|x| n * 100 + x).collect::<Vec<_>>()
+      );
+    });
+  }
+}
@@ -0,0 +1,64 @@
+use crate::internals::awaitable::*;
+use crate::prelude::*;
+use std::sync::{Arc, RwLock};
+
+pub type SingleAsync<'a, Item> = Awaitable<'a, Item>;
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn single_async(&self) -> SingleAsync<'a, Item> {
+    // a second item is enough to tell that the sequence is not single.
+    let source = self.take(2);
+    Awaitable::new(move |sink| {
+      let value = Arc::new(RwLock::new(None));
+      let value_complete = Arc::clone(&value);
+      let sink_error = sink.clone();
+      let sink_complete = sink.clone();
+      source.subscribe(
+        move |x| {
+          let mut value = value.write().unwrap();
+          if value.is_some() {
+            sink.resolve(Err(RxError::from_error(
+              std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "sequence contains more than one element",
+              ),
+            )));
+          } else {
+            *value = Some(x);
+          }
+        },
+        move |e| sink_error.resolve(Err(e)),
+        move || {
+          sink_complete.resolve(
+            value_complete
+              .write()
+              .unwrap()
+              .take()
+              .ok_or_else(no_elements_error),
+          )
+        },
+      )
+    })
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+
+  #[tokio::test]
+  async fn basic() {
+    assert_eq!(
+      observables::just(1).single_async().await.unwrap(),
+      1
+    );
+    assert!(observables::empty::<i32>().single_async().await.is_err());
+    match observables::from_iter(0..).single_async().await {
+      Ok(_) => panic!(),
+      Err(e) => println!("{:?}", e),
+    }
+  }
+}
@@ -0,0 +1,139 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone)]
+pub struct SwitchMap<'a, In, Out>
+where
+  In: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  switchmap_f: FunctionWrapper<'a, In, Observable<'a, Out>>,
+}
+
+impl<'a, In, Out> SwitchMap<'a, In, Out>
+where
+  In: Clone + Send + Sync + 'a,
+  Out: Clone + Send + Sync + 'a,
+{
+  pub fn new<F>(f: F) -> SwitchMap<'a, In, Out>
+  where
+    F: Fn(In) -> Observable<'a, Out> + Send + Sync + 'a,
+  {
+    SwitchMap { switchmap_f: FunctionWrapper::new(f) }
+  }
+  pub fn execute(&self, source: Observable<'a, In>) -> Observable<'a, Out> {
+    let f = self.switchmap_f.clone();
+
+    Observable::create(move |s| {
+      let f = f.clone();
+      // serial of the observer of the latest inner observable.
+      let current = Arc::new(RwLock::new(None::<i32>));
+
+      let sctl = StreamController::new(s);
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          let current_next = Arc::clone(&current);
+          let sctl_next_next = sctl_next.clone();
+          let sctl_next_error = sctl_next.clone();
+          let sctl_next_complete = sctl_next.clone();
+
+          let (serial, observer) = sctl_next.new_observer_with_serial(
+            move |serial, xx| {
+              if *current_next.read().unwrap() == Some(serial) {
+                sctl_next_next.sink_next(xx);
+              }
+            },
+            move |_, ee| {
+              sctl_next_error.sink_error(ee);
+            },
+            move |serial| {
+              sctl_next_complete.sink_complete(&serial);
+            },
+          );
+
+          // the previous inner observable is unsubscribed.
+          let prev = current.write().unwrap().replace(serial);
+          if let Some(prev) = prev {
+            sctl_next.upstream_abort_observe(&prev);
+          }
+          f.call(x).inner_subscribe(observer);
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          sctl_complete.sink_complete(&serial);
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn switch_map<Out, F>(&self, f: F) -> Observable<'a, Out>
+  where
+    F: Fn(Item) -> Observable<'a, Out> + Send + Sync + 'a,
+    Out: Clone + Send + Sync,
+  {
+    SwitchMap::new(f).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let inners = [subjects::Subject::new(), subjects::Subject::new()];
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let completed = Arc::new(RwLock::new(false));
+
+    {
+      let log = Arc::clone(&log);
+      let completed = Arc::clone(&completed);
+      let inners = inners.clone();
+      sbj
+        .observable()
+        .switch_map(move |x: usize| inners[x].observable())
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          move || *completed.write().unwrap() = true,
+        );
+    }
+
+    sbj.next(0);
+    inners[0].next(1);
+    sbj.next(1);
+    inners[0].next(2);
+    inners[1].next(3);
+    sbj.complete();
+    assert!(!*completed.read().unwrap());
+    inners[1].next(4);
+    inners[1].complete();
+    assert!(*completed.read().unwrap());
+    assert_eq!(*log.read().unwrap(), vec![1, 3, 4]);
+  }
+
+  #[test]
+  fn sync_inner() {
+    observables::from_iter(0..3)
+      .switch_map(|x| observables::from_iter(0..x))
+      .subscribe(
+        print_next_fmt!("{}"),
+        print_error!(),
+        print_complete!(),
+      );
+  }
+}
@@ -0,0 +1,362 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use scheduler::ITimedScheduler;
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThrottleConfig {
+  // emit the item that opens a window.
+  pub leading: bool,
+  // emit the latest item received during a window when it closes.
+  pub trailing: bool,
+}
+
+impl Default for ThrottleConfig {
+  fn default() -> Self {
+    ThrottleConfig { leading: true, trailing: false }
+  }
+}
+
+struct ThrottleState<'a, Item> {
+  pending: Option<Item>,
+  throttling: bool,
+  // bumped whenever a window opens, so that a stale timer does nothing.
+  generation: usize,
+  window: Option<Subscription<'a>>,
+}
+
+#[derive(Clone)]
+pub struct Throttle<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  dur: Duration,
+  config: ThrottleConfig,
+  scheduler_ctor: FunctionWrapper<'a, (), Scheduler>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Scheduler, Item> Throttle<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+  Item: Clone + Send + Sync,
+{
+  pub fn new<SchedulerCreator>(
+    dur: Duration,
+    config: ThrottleConfig,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Throttle<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    Throttle {
+      dur,
+      config,
+      scheduler_ctor: FunctionWrapper::new(move |_| scheduler_ctor()),
+      _item: PhantomData,
+    }
+  }
+
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Item> {
+    let dur = self.dur;
+    let config = self.config;
+    let scheduler_ctor = self.scheduler_ctor.clone();
+
+    Observable::create(move |s| {
+      fn open_window<'a, Scheduler, Item>(
+        dur: Duration,
+        trailing: bool,
+        scheduler: Scheduler,
+        state: Arc<RwLock<ThrottleState<'a, Item>>>,
+        sctl: StreamController<'a, Item>,
+      ) where
+        Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+        Item: Clone + Send + Sync,
+      {
+        let generation = {
+          let mut state = state.write().unwrap();
+          state.throttling = true;
+          state.generation += 1;
+          state.generation
+        };
+        let window = {
+          let scheduler_f = scheduler.clone();
+          let state = Arc::clone(&state);
+          let sctl = sctl.clone();
+          scheduler.schedule_after(dur, move || {
+            let pending = {
+              let mut state = state.write().unwrap();
+              if state.generation != generation {
+                return;
+              }
+              state.throttling = false;
+              state.window = None;
+              if trailing {
+                state.pending.take()
+              } else {
+                None
+              }
+            };
+            // a trailing emission opens the next window.
+            if let Some(pending) = pending {
+              sctl.sink_next(pending);
+              open_window(
+                dur,
+                trailing,
+                scheduler_f.clone(),
+                Arc::clone(&state),
+                sctl.clone(),
+              );
+            }
+          })
+        };
+        let mut state = state.write().unwrap();
+        if state.generation == generation && state.throttling {
+          state.window = Some(window);
+        }
+      }
+
+      let sctl = StreamController::new(s);
+      let scheduler = scheduler_ctor.call(());
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let state = Arc::new(RwLock::new(ThrottleState {
+        pending: None::<Item>,
+        throttling: false,
+        generation: 0,
+        window: None,
+      }));
+
+      let state_next = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          let opening = {
+            let mut state = state_next.write().unwrap();
+            if state.throttling {
+              if config.trailing {
+                state.pending = Some(x);
+              }
+              return;
+            }
+            if config.leading {
+              Some(x)
+            } else {
+              state.pending = Some(x);
+              None
+            }
+          };
+          open_window(
+            dur,
+            config.trailing,
+            scheduler.clone(),
+            Arc::clone(&state_next),
+            sctl_next.clone(),
+          );
+          if let Some(x) = opening {
+            sctl_next.sink_next(x);
+          }
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          let (pending, window) = {
+            let mut state = state_complete.write().unwrap();
+            state.generation += 1;
+            state.throttling = false;
+            (
+              state.pending.take(),
+              state.window.take(),
+            )
+          };
+          if let Some(window) = window {
+            window.unsubscribe();
+          }
+          if let Some(pending) = pending {
+            sctl_complete.sink_next(pending);
+          }
+          sctl_complete.sink_complete(&serial);
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn throttle<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    config: ThrottleConfig,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    Throttle::new(dur, config, scheduler_ctor).execute(self.clone())
+  }
+
+  pub fn throttle_first<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    self.throttle(
+      dur,
+      ThrottleConfig { leading: true, trailing: false },
+      scheduler_ctor,
+    )
+  }
+
+  pub fn throttle_last<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    self.throttle(
+      dur,
+      ThrottleConfig { leading: false, trailing: true },
+      scheduler_ctor,
+    )
+  }
+
+  pub fn audit<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Item>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    self.throttle_last(dur, scheduler_ctor)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  fn run<F>(f: F) -> Vec<i32>
+  where
+    F: Fn(
+      Observable<'static, i32>,
+      &schedulers::TestScheduler<'static>,
+    ) -> Observable<'static, i32>,
+  {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      f(sbj.observable(), &ts).subscribe(
+        move |x| log.write().unwrap().push(x),
+        junk_error!(),
+        junk_complete!(),
+      );
+    }
+
+    // 0:0, 30:1, 60:2, 120:3, 250:4, 270:5
+    sbj.next(0);
+    ts.advance_by(time::Duration::from_millis(30));
+    sbj.next(1);
+    ts.advance_by(time::Duration::from_millis(30));
+    sbj.next(2);
+    ts.advance_by(time::Duration::from_millis(60));
+    sbj.next(3);
+    ts.advance_by(time::Duration::from_millis(130));
+    sbj.next(4);
+    ts.advance_by(time::Duration::from_millis(20));
+    sbj.next(5);
+    ts.advance_by(time::Duration::from_millis(200));
+    sbj.complete();
+
+    let log = log.read().unwrap().clone();
+    log
+  }
+
+  #[test]
+  fn throttle_first() {
+    let log = run(|o, ts| {
+      o.throttle_first(
+        time::Duration::from_millis(100),
+        ts.scheduler_ctor(),
+      )
+    });
+    assert_eq!(log, vec![0, 3, 4]);
+  }
+
+  #[test]
+  fn throttle_last() {
+    let log = run(|o, ts| {
+      o.throttle_last(
+        time::Duration::from_millis(100),
+        ts.scheduler_ctor(),
+      )
+    });
+    // a trailing emission opens the next window.
+    // windows: 0-100 (2), 100-200 (3), 200-300 (5)
+    assert_eq!(log, vec![2, 3, 5]);
+  }
+
+  #[test]
+  fn leading_and_trailing() {
+    let log = run(|o, ts| {
+      o.throttle(
+        time::Duration::from_millis(100),
+        operators::ThrottleConfig { leading: true, trailing: true },
+        ts.scheduler_ctor(),
+      )
+    });
+    // windows: 0-100 (0, 2), 100-200 (3), 200-300 (5)
+    assert_eq!(log, vec![0, 2, 3, 5]);
+  }
+
+  #[test]
+  fn thread() {
+    observables::interval(
+      time::Duration::from_millis(10),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(30)
+    .audit(
+      time::Duration::from_millis(100),
+      schedulers::new_thread_scheduler(),
+    )
+    .subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+    thread::sleep(time::Duration::from_millis(700));
+  }
+}
@@ -1,6 +1,6 @@
 use crate::internals::{function_wrapper::*, stream_controller::*};
 use crate::prelude::*;
-use scheduler::IScheduler;
+use scheduler::ITimedScheduler;
 use std::marker::PhantomData;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
@@ -8,7 +8,7 @@ use std::time::Duration;
 #[derive(Clone)]
 pub struct Timeout<'a, Scheduler, Item>
 where
-  Scheduler: IScheduler<'a> + Clone + Send + Sync,
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
   Item: Clone + Send + Sync,
 {
   dur: Duration,
@@ -18,7 +18,7 @@ where
 
 impl<'a, Scheduler, Item> Timeout<'a, Scheduler, Item>
 where
-  Scheduler: IScheduler<'a> + Clone + Send + Sync,
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
   Item: Clone + Send + Sync,
 {
   pub fn new<SchedulerCreator>(
@@ -41,40 +41,33 @@ where
     Observable::create(move |s| {
       let sctl = StreamController::new(s);
       let timer = Arc::new(RwLock::new(None::<Subscription<'a>>));
-      let scheduler_ctor = scheduler_ctor.clone();
+
+      let scheduler = scheduler_ctor.call(());
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
 
       let sctl_next = sctl.clone();
       let sctl_error = sctl.clone();
       let sctl_complete = sctl.clone();
       source.inner_subscribe(sctl.new_observer(
         move |_, x| {
-          {
-            let mut timer = timer.write().unwrap();
-            if let Some(timer) = &*timer {
-              timer.unsubscribe();
-            }
-            *timer = None;
+          if let Some(timer) = timer.write().unwrap().take() {
+            timer.unsubscribe();
           }
 
           sctl_next.sink_next(x);
 
-          {
-            let sctl = sctl_next.clone();
-            let scheduler_ctor = scheduler_ctor.clone();
-            *timer.write().unwrap() = Some(
-              observables::interval(dur, move || scheduler_ctor.call(()))
-                .take(1)
-                .subscribe(
-                  move |_| {
-                    sctl.sink_error(RxError::from_error(
-                      std::io::Error::from(std::io::ErrorKind::TimedOut),
-                    ));
-                  },
-                  junk_error!(),
-                  junk_complete!(),
-                ),
-            );
-          }
+          let sctl = sctl_next.clone();
+          *timer.write().unwrap() =
+            Some(scheduler.schedule_after(dur, move || {
+              sctl.sink_error(RxError::from_error(
+                std::io::Error::from(std::io::ErrorKind::TimedOut),
+              ));
+            }));
         },
         move |_, e| {
           sctl_error.sink_error(e);
@@ -95,7 +88,7 @@ where
     scheduler_ctor: SchedulerCreator,
   ) -> Observable<'a, Item>
   where
-    Scheduler: IScheduler<'a> + Clone + Send + Sync + 'a,
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
     SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
   {
     Timeout::new(dur, scheduler_ctor).execute(self.clone())
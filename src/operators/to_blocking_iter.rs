@@ -0,0 +1,234 @@
+use crate::internals::awaitable::no_elements_error;
+use crate::prelude::*;
+use std::sync::mpsc;
+
+pub struct BlockingIter<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  source: Option<Observable<'a, Item>>,
+  rx: Option<mpsc::Receiver<Material<Item>>>,
+  subscription: Option<Subscription<'a>>,
+}
+
+impl<'a, Item> BlockingIter<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  fn start(&mut self, source: Observable<'a, Item>) {
+    let (tx, rx) = mpsc::channel();
+    let tx_error = tx.clone();
+    let tx_complete = tx.clone();
+    self.rx = Some(rx);
+    self.subscription = Some(source.subscribe(
+      move |x| {
+        let _ = tx.send(Material::Next(x));
+      },
+      move |e| {
+        let _ = tx_error.send(Material::Error(e));
+      },
+      move || {
+        let _ = tx_complete.send(Material::Complete);
+      },
+    ));
+  }
+}
+
+impl<'a, Item> Iterator for BlockingIter<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  type Item = Result<Item, RxError>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    // subscribe lazily, on the first call.
+    if let Some(source) = self.source.take() {
+      self.start(source);
+    }
+
+    let item = match self.rx.as_ref()?.recv() {
+      Ok(Material::Next(x)) => return Some(Ok(x)),
+      Ok(Material::Error(e)) => Some(Err(e)),
+      Ok(Material::Complete) | Err(_) => None,
+    };
+    // the sequence has terminated.
+    self.rx = None;
+    item
+  }
+}
+
+impl<'a, Item> Drop for BlockingIter<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  fn drop(&mut self) {
+    if let Some(subscription) = self.subscription.take() {
+      subscription.unsubscribe();
+    }
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn to_blocking_iter(&self) -> BlockingIter<'a, Item> {
+    BlockingIter {
+      source: Some(self.clone()),
+      rx: None,
+      subscription: None,
+    }
+  }
+
+  pub fn blocking_first(&self) -> Result<Item, RxError> {
+    self
+      .first()
+      .to_blocking_iter()
+      .next()
+      .unwrap_or_else(|| Err(no_elements_error()))
+  }
+
+  pub fn blocking_last(&self) -> Result<Item, RxError> {
+    self
+      .last()
+      .to_blocking_iter()
+      .next()
+      .unwrap_or_else(|| Err(no_elements_error()))
+  }
+
+  // Waits for the sequence to terminate. The callbacks run on the calling
+  // thread.
+  pub fn blocking_subscribe<Next, Error, Complete>(
+    &self,
+    next: Next,
+    error: Error,
+    complete: Complete,
+  ) where
+    Next: Fn(Item),
+    Error: Fn(RxError),
+    Complete: Fn(),
+  {
+    for x in self.to_blocking_iter() {
+      match x {
+        Ok(x) => next(x),
+        Err(e) => {
+          error(e);
+          return;
+        }
+      }
+    }
+    complete();
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let v = observables::from_iter(0..5)
+      .to_blocking_iter()
+      .collect::<Result<Vec<_>, _>>();
+    assert_eq!(v.unwrap(), vec![0, 1, 2, 3, 4]);
+  }
+
+  #[test]
+  fn thread() {
+    observables::interval(
+      time::Duration::from_millis(50),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(3)
+    .to_blocking_iter()
+    .for_each(|x| println!("{:?}", x));
+  }
+
+  #[test]
+  fn error() {
+    let mut it = observables::just(1)
+      .concat(&[observables::error(RxError::from_error("ERR!"))])
+      .to_blocking_iter();
+    assert_eq!(it.next().unwrap().unwrap(), 1);
+    assert!(it.next().unwrap().is_err());
+    assert!(it.next().is_none());
+  }
+
+  #[test]
+  fn drop_unsubscribes() {
+    let count = Arc::new(RwLock::new(0));
+    {
+      let count = Arc::clone(&count);
+      let it = observables::interval(
+        time::Duration::from_millis(20),
+        schedulers::new_thread_scheduler(),
+      )
+      .tap(
+        move |_| *count.write().unwrap() += 1,
+        |_| {},
+        || {},
+      )
+      .to_blocking_iter();
+      assert_eq!(it.take(3).count(), 3);
+    }
+    let n = *count.read().unwrap();
+    thread::sleep(time::Duration::from_millis(100));
+    assert_eq!(*count.read().unwrap(), n);
+  }
+
+  #[test]
+  fn blocking_first() {
+    assert_eq!(
+      observables::from_iter(3..).blocking_first().unwrap(),
+      3
+    );
+    assert!(observables::empty::<i32>().blocking_first().is_err());
+
+    let x = observables::interval(
+      time::Duration::from_millis(50),
+      schedulers::new_thread_scheduler(),
+    )
+    .skip(2)
+    .blocking_first();
+    assert_eq!(x.unwrap(), 2);
+  }
+
+  #[test]
+  fn blocking_last() {
+    assert_eq!(
+      observables::from_iter(0..5).blocking_last().unwrap(),
+      4
+    );
+    assert!(observables::empty::<i32>().blocking_last().is_err());
+
+    let x = observables::interval(
+      time::Duration::from_millis(50),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(3)
+    .blocking_last();
+    assert_eq!(x.unwrap(), 2);
+  }
+
+  #[test]
+  fn blocking_subscribe() {
+    let id = thread::current().id();
+    observables::interval(
+      time::Duration::from_millis(50),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(3)
+    .blocking_subscribe(
+      move |x| {
+        assert_eq!(thread::current().id(), id);
+        println!("next - {}", x);
+      },
+      print_error!(),
+      print_complete!(),
+    );
+  }
+}
@@ -1,104 +1,44 @@
+use crate::internals::awaitable::*;
 use crate::prelude::*;
-use std::{
-  future::Future,
-  marker::PhantomData,
-  sync::{Arc, RwLock},
-  task::{self, Waker},
-};
+use std::sync::{Arc, RwLock};
 
-#[derive(Clone)]
-pub struct ToVec<'a, Item>
-where
-  Item: Clone + Send + Sync,
-{
-  buffer: Arc<RwLock<Vec<Item>>>,
-  done: Arc<RwLock<bool>>,
-  err: Arc<RwLock<Option<RxError>>>,
-  waker: Arc<RwLock<Option<Waker>>>,
-  _lifetime: PhantomData<&'a ()>,
-}
-
-impl<'a, Item> ToVec<'a, Item>
-where
-  Item: Clone + Send + Sync,
-{
-  fn start(&self, source: Observable<'a, Item>) {
-    let buff_next = Arc::clone(&self.buffer);
-    let done_error = Arc::clone(&self.done);
-    let done_complete = Arc::clone(&self.done);
-    let err_error = Arc::clone(&self.err);
-    let waker_error = Arc::clone(&self.waker);
-    let waker_complete = Arc::clone(&self.waker);
-    source.subscribe(
-      move |x| buff_next.write().unwrap().push(x),
-      move |e| {
-        *err_error.write().unwrap() = Some(e);
-        *done_error.write().unwrap() = true;
-        if let Some(w) = waker_error.read().unwrap().clone() {
-          w.wake();
-        }
-      },
-      move || {
-        *done_complete.write().unwrap() = true;
-        if let Some(w) = waker_complete.read().unwrap().clone() {
-          w.wake();
-        }
-      },
-    );
-  }
-}
-
-impl<'a, Item> Future for ToVec<'a, Item>
-where
-  Item: Clone + Send + Sync,
-{
-  type Output = Result<Arc<RwLock<Vec<Item>>>, RxError>;
-
-  fn poll(
-    self: std::pin::Pin<&mut Self>,
-    cx: &mut std::task::Context<'_>,
-  ) -> std::task::Poll<Self::Output> {
-    let mut waker = self.waker.write().unwrap();
-
-    if *self.done.read().unwrap() {
-      if let Some(err) = &*self.err.read().unwrap() {
-        task::Poll::Ready(Err(err.clone()))
-      } else {
-        task::Poll::Ready(Ok(Arc::clone(&self.buffer)))
-      }
-    } else {
-      *waker = Some(cx.waker().clone());
-      task::Poll::Pending
-    }
-  }
-}
+pub type ToVec<'a, Item> = Awaitable<'a, Vec<Item>>;
 
 impl<'a, Item> Observable<'a, Item>
 where
   Item: Clone + Send + Sync,
 {
-  pub fn to_vec(&self) -> ToVec<Item> {
-    let inst = ToVec {
-      buffer: Arc::new(RwLock::new(Vec::new())),
-      done: Arc::new(RwLock::new(false)),
-      err: Arc::new(RwLock::new(None)),
-      waker: Arc::new(RwLock::new(None)),
-      _lifetime: PhantomData,
-    };
-    inst.start(self.clone());
-    inst
+  pub fn to_vec(&self) -> ToVec<'a, Item> {
+    let source = self.clone();
+    Awaitable::new(move |sink| {
+      let buffer = Arc::new(RwLock::new(Vec::new()));
+      let buffer_complete = Arc::clone(&buffer);
+      let sink_complete = sink.clone();
+      source.subscribe(
+        move |x| buffer.write().unwrap().push(x),
+        move |e| sink.resolve(Err(e)),
+        move || {
+          sink_complete.resolve(Ok(std::mem::take(
+            &mut *buffer_complete.write().unwrap(),
+          )))
+        },
+      )
+    })
   }
 }
 
 #[cfg(all(test, not(feature = "web")))]
 mod test {
   use crate::prelude::*;
-  use std::time;
+  use std::{
+    sync::{Arc, RwLock},
+    time,
+  };
 
   #[tokio::test]
   async fn basic() {
     match observables::just(1).to_vec().await {
-      Ok(v) => println!("Ok -> {:?}", v.read().unwrap()),
+      Ok(v) => println!("Ok -> {:?}", v),
       Err(e) => println!("Err -> {:?}", e.downcast_ref::<&str>()),
     }
   }
@@ -113,7 +53,7 @@ mod test {
     .to_vec()
     .await
     {
-      Ok(v) => println!("Ok -> {:?}", v.read().unwrap()),
+      Ok(v) => println!("Ok -> {:?}", v),
       Err(e) => println!("Err -> {:?}", e.downcast_ref::<&str>()),
     }
   }
@@ -135,8 +75,24 @@ mod test {
     .to_vec()
     .await
     {
-      Ok(v) => println!("Ok -> {:?}", v.read().unwrap()),
+      Ok(v) => println!("Ok -> {:?}", v),
       Err(e) => println!("Err -> {:?}", e.downcast_ref::<&str>()),
     }
   }
+
+  #[tokio::test]
+  async fn lazy() {
+    let subscribed = Arc::new(RwLock::new(false));
+    let fut = {
+      let subscribed = Arc::clone(&subscribed);
+      observables::defer(move || {
+        *subscribed.write().unwrap() = true;
+        observables::from_iter(0..3)
+      })
+      .to_vec()
+    };
+    assert!(!*subscribed.read().unwrap());
+    assert_eq!(fut.await.unwrap(), vec![0, 1, 2]);
+    assert!(*subscribed.read().unwrap());
+  }
 }
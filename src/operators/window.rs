@@ -0,0 +1,153 @@
+use crate::internals::stream_controller::*;
+use crate::prelude::*;
+use std::{
+  marker::PhantomData,
+  sync::{Arc, RwLock},
+};
+
+#[derive(Clone)]
+pub struct Window<'a, Item, Boundary>
+where
+  Item: Clone + Send + Sync,
+  Boundary: Clone + Send + Sync,
+{
+  boundary: Observable<'a, Boundary>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Item, Boundary> Window<'a, Item, Boundary>
+where
+  Item: Clone + Send + Sync,
+  Boundary: Clone + Send + Sync,
+{
+  pub fn new(boundary: Observable<'a, Boundary>) -> Window<'a, Item, Boundary> {
+    Window { boundary, _item: PhantomData }
+  }
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Observable<'a, Item>> {
+    let boundary = self.boundary.clone();
+    Observable::create(move |s| {
+      let sbj = Arc::new(RwLock::new(
+        subjects::Subject::<Item>::new(),
+      ));
+      let sctl = StreamController::new(s);
+
+      // the first window opens right away.
+      sctl.sink_next(sbj.read().unwrap().observable());
+
+      let obs_boundary = {
+        let sbj_next = Arc::clone(&sbj);
+        let sbj_error = Arc::clone(&sbj);
+        let sbj_complete = Arc::clone(&sbj);
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, _| {
+            let prev = std::mem::replace(
+              &mut *sbj_next.write().unwrap(),
+              subjects::Subject::<Item>::new(),
+            );
+            prev.complete();
+            sctl_next.sink_next(sbj_next.read().unwrap().observable());
+          },
+          move |_, e| {
+            sbj_error.read().unwrap().error(e.clone());
+            sctl_error.sink_error(e);
+          },
+          move |_| {
+            sbj_complete.read().unwrap().complete();
+            sctl_complete.sink_complete_force();
+          },
+        )
+      };
+
+      let obs_source = {
+        let sbj_next = Arc::clone(&sbj);
+        let sbj_error = Arc::clone(&sbj);
+        let sbj_complete = Arc::clone(&sbj);
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x| {
+            let sbj = sbj_next.read().unwrap().clone();
+            sbj.next(x);
+          },
+          move |_, e| {
+            sbj_error.read().unwrap().error(e.clone());
+            sctl_error.sink_error(e);
+          },
+          move |_| {
+            sbj_complete.read().unwrap().complete();
+            sctl_complete.sink_complete_force();
+          },
+        )
+      };
+
+      boundary.inner_subscribe(obs_boundary);
+      source.inner_subscribe(obs_source);
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn window<Boundary>(
+    &self,
+    boundary: Observable<'a, Boundary>,
+  ) -> Observable<'a, Observable<'a, Item>>
+  where
+    Boundary: Clone + Send + Sync,
+  {
+    Window::new(boundary).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let boundary = subjects::Subject::<()>::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      let n = Arc::new(RwLock::new(0));
+      sbj.observable().window(boundary.observable()).subscribe(
+        move |x| {
+          let nn = *n.read().unwrap();
+          *n.write().unwrap() += 1;
+          let log = Arc::clone(&log);
+          x.subscribe(
+            move |y| log.write().unwrap().push((nn, y)),
+            move |e| println!("error ({}) - {:?}", nn, e),
+            move || println!("complete ({})", nn),
+          );
+        },
+        print_error!(),
+        print_complete!(),
+      );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    boundary.next(());
+    sbj.next(2);
+    boundary.next(());
+    boundary.next(());
+    sbj.next(3);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![(0, 0), (0, 1), (1, 2), (3, 3)]
+    );
+  }
+}
@@ -0,0 +1,238 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::{
+  collections::BTreeMap,
+  marker::PhantomData,
+  sync::{Arc, RwLock},
+};
+
+struct WindowToggleState<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  windows: BTreeMap<usize, subjects::Subject<'a, Item>>,
+  serial: usize,
+}
+
+#[derive(Clone)]
+pub struct WindowToggle<'a, Item, Opening, Closing>
+where
+  Item: Clone + Send + Sync,
+  Opening: Clone + Send + Sync,
+  Closing: Clone + Send + Sync,
+{
+  openings: Observable<'a, Opening>,
+  closing_selector: FunctionWrapper<'a, Opening, Observable<'a, Closing>>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Item, Opening, Closing> WindowToggle<'a, Item, Opening, Closing>
+where
+  Item: Clone + Send + Sync,
+  Opening: Clone + Send + Sync,
+  Closing: Clone + Send + Sync,
+{
+  pub fn new<F>(
+    openings: Observable<'a, Opening>,
+    closing_selector: F,
+  ) -> WindowToggle<'a, Item, Opening, Closing>
+  where
+    F: Fn(Opening) -> Observable<'a, Closing> + Send + Sync + 'a,
+  {
+    WindowToggle {
+      openings,
+      closing_selector: FunctionWrapper::new(closing_selector),
+      _item: PhantomData,
+    }
+  }
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Observable<'a, Item>> {
+    let openings = self.openings.clone();
+    let closing_selector = self.closing_selector.clone();
+
+    Observable::create(move |s| {
+      let state = Arc::new(RwLock::new(WindowToggleState {
+        windows: BTreeMap::new(),
+        serial: 0,
+      }));
+      let sctl = StreamController::new(s);
+
+      let take_windows = {
+        let state = Arc::clone(&state);
+        move || std::mem::take(&mut state.write().unwrap().windows)
+      };
+
+      let obs_openings = {
+        let state = Arc::clone(&state);
+        let take_windows_next = take_windows.clone();
+        let take_windows_error = take_windows.clone();
+        let closing_selector = closing_selector.clone();
+        let sctl_next = sctl.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x| {
+            let sbj = subjects::Subject::<Item>::new();
+            let id = {
+              let mut state = state.write().unwrap();
+              let id = state.serial;
+              state.serial += 1;
+              state.windows.insert(id, sbj.clone());
+              id
+            };
+            sctl_next.sink_next(sbj.observable());
+
+            // the window is closed by the first item or the completion of
+            // its closing observable.
+            let close = {
+              let state = Arc::clone(&state);
+              let sctl = sctl_next.clone();
+              move |serial| {
+                sctl.upstream_abort_observe(&serial);
+                let sbj = state.write().unwrap().windows.remove(&id);
+                if let Some(sbj) = sbj {
+                  sbj.complete();
+                }
+              }
+            };
+            let close_next = close.clone();
+            let take_windows = take_windows_next.clone();
+            let sctl_closing_error = sctl_next.clone();
+            closing_selector
+              .call(x)
+              .inner_subscribe(sctl_next.new_observer(
+                move |serial, _| close_next(serial),
+                move |_, e| {
+                  take_windows().values().for_each(|sbj| sbj.error(e.clone()));
+                  sctl_closing_error.sink_error(e);
+                },
+                close,
+              ));
+          },
+          move |_, e| {
+            take_windows_error()
+              .values()
+              .for_each(|sbj| sbj.error(e.clone()));
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+          },
+        )
+      };
+
+      let obs_source = {
+        let state = Arc::clone(&state);
+        let take_windows_error = take_windows.clone();
+        let take_windows_complete = take_windows.clone();
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        sctl.new_observer(
+          move |_, x: Item| {
+            let windows = state
+              .read()
+              .unwrap()
+              .windows
+              .values()
+              .cloned()
+              .collect::<Vec<_>>();
+            windows.iter().for_each(|sbj| sbj.next(x.clone()));
+          },
+          move |_, e| {
+            take_windows_error()
+              .values()
+              .for_each(|sbj| sbj.error(e.clone()));
+            sctl_error.sink_error(e);
+          },
+          move |_| {
+            take_windows_complete()
+              .values()
+              .for_each(|sbj| sbj.complete());
+            sctl_complete.sink_complete_force();
+          },
+        )
+      };
+
+      openings.inner_subscribe(obs_openings);
+      source.inner_subscribe(obs_source);
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn window_toggle<Opening, Closing, F>(
+    &self,
+    openings: Observable<'a, Opening>,
+    closing_selector: F,
+  ) -> Observable<'a, Observable<'a, Item>>
+  where
+    Opening: Clone + Send + Sync + 'a,
+    Closing: Clone + Send + Sync + 'a,
+    F: Fn(Opening) -> Observable<'a, Closing> + Send + Sync + 'a,
+  {
+    WindowToggle::new(openings, closing_selector).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let openings = subjects::Subject::<i32>::new();
+    let closings = [subjects::Subject::<()>::new(), subjects::Subject::new()];
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      let closings = closings.clone();
+      sbj
+        .observable()
+        .window_toggle(openings.observable(), move |n| {
+          closings[n as usize].observable()
+        })
+        .subscribe(
+          move |x| {
+            let log = Arc::clone(&log);
+            let items = Arc::new(RwLock::new(Vec::new()));
+            let items_complete = Arc::clone(&items);
+            x.subscribe(
+              move |y| items.write().unwrap().push(y),
+              junk_error!(),
+              move || {
+                log
+                  .write()
+                  .unwrap()
+                  .push(items_complete.read().unwrap().clone())
+              },
+            );
+          },
+          print_error!(),
+          print_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    openings.next(0);
+    sbj.next(1);
+    openings.next(1);
+    sbj.next(2);
+    closings[0].next(());
+    sbj.next(3);
+    closings[1].complete();
+    sbj.next(4);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![1, 2], vec![2, 3]]
+    );
+  }
+}
@@ -1,6 +1,7 @@
 use crate::internals::stream_controller::*;
 use crate::prelude::*;
 use std::{
+  collections::VecDeque,
   marker::PhantomData,
   sync::{Arc, RwLock},
 };
@@ -11,6 +12,7 @@ where
   Item: Clone + Send + Sync,
 {
   count: usize,
+  skip: usize,
   _item: PhantomData<Item>,
 }
 
@@ -18,53 +20,82 @@ impl<'a, Item> WindowWithCount<Item>
 where
   Item: Clone + Send + Sync,
 {
-  pub fn new(count: usize) -> WindowWithCount<Item> {
-    WindowWithCount { count, _item: PhantomData }
+  pub fn new(count: usize, skip: usize) -> WindowWithCount<Item> {
+    assert!(count > 0);
+    assert!(skip > 0);
+    WindowWithCount { count, skip, _item: PhantomData }
   }
   pub fn execute(
     &self,
     source: Observable<'a, Item>,
   ) -> Observable<'a, Observable<'a, Item>> {
     let count = self.count;
+    let skip = self.skip;
 
     Observable::create(move |s| {
-      let n = Arc::new(RwLock::new(0));
-      let sbj = Arc::new(RwLock::new(
-        subjects::Subject::<Item>::new(),
-      ));
+      // each open window is kept with the number of items it has received.
+      // a new window is opened every `skip` items.
+      let n = Arc::new(RwLock::new(0usize));
+      let windows = Arc::new(RwLock::new(VecDeque::<(
+        usize,
+        subjects::Subject<Item>,
+      )>::new()));
 
       let sctl = StreamController::new(s);
       let sctl_next = sctl.clone();
       let sctl_error = sctl.clone();
       let sctl_complete = sctl.clone();
 
-      let sbj_next = Arc::clone(&sbj);
-      let sbj_error = Arc::clone(&sbj);
-      let sbj_complete = Arc::clone(&sbj);
+      let windows_next = Arc::clone(&windows);
+      let windows_error = Arc::clone(&windows);
+      let windows_complete = Arc::clone(&windows);
 
       source.inner_subscribe(sctl.new_observer(
-        move |_, x| {
-          let mut n = n.write().unwrap();
-          if *n == 0 {
-            sctl_next.sink_next(sbj_next.read().unwrap().observable());
-            sbj_next.read().unwrap().next(x);
-            *n += 1;
-          } else {
-            sbj_next.read().unwrap().next(x);
-            *n += 1;
-            if *n == count {
-              sbj_next.read().unwrap().complete();
-              *sbj_next.write().unwrap() = subjects::Subject::<Item>::new();
-              *n = 0;
-            }
+        move |_, x: Item| {
+          let opened = {
+            let mut n = n.write().unwrap();
+            let opened = if *n == 0 {
+              let sbj = subjects::Subject::<Item>::new();
+              windows_next.write().unwrap().push_back((0, sbj.clone()));
+              Some(sbj)
+            } else {
+              None
+            };
+            *n = (*n + 1) % skip;
+            opened
+          };
+          if let Some(sbj) = opened {
+            sctl_next.sink_next(sbj.observable());
+          }
+
+          let (targets, closed) = {
+            let mut windows = windows_next.write().unwrap();
+            windows.iter_mut().for_each(|(len, _)| *len += 1);
+            let targets = windows
+              .iter()
+              .map(|(_, sbj)| sbj.clone())
+              .collect::<Vec<_>>();
+            let closed =
+              if windows.front().is_some_and(|(len, _)| *len == count) {
+                windows.pop_front().map(|(_, sbj)| sbj)
+              } else {
+                None
+              };
+            (targets, closed)
+          };
+          targets.iter().for_each(|sbj| sbj.next(x.clone()));
+          if let Some(sbj) = closed {
+            sbj.complete();
           }
         },
         move |_, e| {
-          sbj_error.read().unwrap().error(e.clone());
+          let windows = std::mem::take(&mut *windows_error.write().unwrap());
+          windows.iter().for_each(|(_, sbj)| sbj.error(e.clone()));
           sctl_error.sink_error(e);
         },
         move |serial| {
-          sbj_complete.read().unwrap().complete();
+          let windows = std::mem::take(&mut *windows_complete.write().unwrap());
+          windows.iter().for_each(|(_, sbj)| sbj.complete());
           sctl_complete.sink_complete(&serial);
         },
       ));
@@ -79,8 +110,9 @@ where
   pub fn window_with_count(
     &self,
     count: usize,
+    skip: usize,
   ) -> Observable<'a, Observable<'a, Item>> {
-    WindowWithCount::new(count).execute(self.clone())
+    WindowWithCount::new(count, skip).execute(self.clone())
   }
 }
 
@@ -93,7 +125,7 @@ mod test {
   fn basic() {
     let n = Arc::new(RwLock::new(0));
     observables::from_iter(0..10)
-      .window_with_count(3)
+      .window_with_count(3, 3)
       .subscribe(
         move |x| {
           let nn = *n.read().unwrap();
@@ -120,7 +152,7 @@ mod test {
           observables::just(x)
         }
       })
-      .window_with_count(3)
+      .window_with_count(3, 3)
       .subscribe(
         move |x| {
           let nn = *n.read().unwrap();
@@ -135,4 +167,37 @@ mod test {
         print_complete!(),
       );
   }
+
+  #[test]
+  fn sliding() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(0..5)
+        .window_with_count(3, 2)
+        .subscribe(
+          move |x| {
+            let log = Arc::clone(&log);
+            let items = Arc::new(RwLock::new(Vec::new()));
+            let items_complete = Arc::clone(&items);
+            x.subscribe(
+              move |y| items.write().unwrap().push(y),
+              junk_error!(),
+              move || {
+                log
+                  .write()
+                  .unwrap()
+                  .push(items_complete.read().unwrap().clone())
+              },
+            );
+          },
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1, 2], vec![2, 3, 4], vec![4]]
+    );
+  }
 }
@@ -0,0 +1,134 @@
+use crate::prelude::*;
+use scheduler::ITimedScheduler;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct WindowWithTime<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  window_op: operators::WindowWithTimeOrCount<'a, Scheduler, Item>,
+}
+
+impl<'a, Scheduler, Item> WindowWithTime<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+  Item: Clone + Send + Sync,
+{
+  pub fn new<SchedulerCreator>(
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> WindowWithTime<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    WindowWithTime {
+      window_op: operators::WindowWithTimeOrCount::new(
+        dur,
+        usize::MAX,
+        scheduler_ctor,
+      ),
+    }
+  }
+
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Observable<'a, Item>> {
+    self.window_op.execute(source)
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn window_with_time<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Observable<'a, Item>>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    WindowWithTime::new(dur, scheduler_ctor).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .window_with_time(
+          time::Duration::from_millis(100),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| {
+            let log = Arc::clone(&log);
+            let items = Arc::new(RwLock::new(Vec::new()));
+            let items_complete = Arc::clone(&items);
+            x.subscribe(
+              move |y| items.write().unwrap().push(y),
+              junk_error!(),
+              move || {
+                log
+                  .write()
+                  .unwrap()
+                  .push(items_complete.read().unwrap().clone())
+              },
+            );
+          },
+          junk_error!(),
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    sbj.next(1);
+    ts.advance_by(time::Duration::from_millis(100));
+    ts.advance_by(time::Duration::from_millis(100));
+    sbj.next(2);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1], vec![], vec![2]]
+    );
+  }
+
+  #[test]
+  fn thread() {
+    observables::interval(
+      time::Duration::from_millis(30),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(10)
+    .window_with_time(
+      time::Duration::from_millis(100),
+      schedulers::new_thread_scheduler(),
+    )
+    .flat_map(|x| x.count())
+    .subscribe(
+      print_next_fmt!("{:?}"),
+      print_error!(),
+      print_complete!(),
+    );
+    thread::sleep(time::Duration::from_millis(500));
+  }
+}
@@ -0,0 +1,284 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use scheduler::ITimedScheduler;
+use std::{
+  marker::PhantomData,
+  sync::{Arc, RwLock},
+  time::Duration,
+};
+
+struct WindowState<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  sbj: subjects::Subject<'a, Item>,
+  n: usize,
+  // bumped whenever the window is closed by count, so that the timer of
+  // the previous period does not close the new window early.
+  generation: usize,
+}
+
+#[derive(Clone)]
+pub struct WindowWithTimeOrCount<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync,
+  Item: Clone + Send + Sync,
+{
+  dur: Duration,
+  count: usize,
+  scheduler_ctor: FunctionWrapper<'a, (), Scheduler>,
+  _item: PhantomData<Item>,
+}
+
+impl<'a, Scheduler, Item> WindowWithTimeOrCount<'a, Scheduler, Item>
+where
+  Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+  Item: Clone + Send + Sync,
+{
+  pub fn new<SchedulerCreator>(
+    dur: Duration,
+    count: usize,
+    scheduler_ctor: SchedulerCreator,
+  ) -> WindowWithTimeOrCount<'a, Scheduler, Item>
+  where
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    assert!(count > 0);
+    WindowWithTimeOrCount {
+      dur,
+      count,
+      scheduler_ctor: FunctionWrapper::new(move |_| scheduler_ctor()),
+      _item: PhantomData,
+    }
+  }
+
+  pub fn execute(
+    &self,
+    source: Observable<'a, Item>,
+  ) -> Observable<'a, Observable<'a, Item>> {
+    let dur = self.dur;
+    let count = self.count;
+    let scheduler_ctor = self.scheduler_ctor.clone();
+
+    Observable::create(move |s| {
+      // a timer passes the generation it was armed with, and the window is
+      // only swapped if no count-triggered close has happened since.
+      fn next_window<'a, Item>(
+        state: &Arc<RwLock<WindowState<'a, Item>>>,
+        sctl: &StreamController<'a, Observable<'a, Item>>,
+        generation: Option<usize>,
+      ) where
+        Item: Clone + Send + Sync,
+      {
+        let (prev, sbj) = {
+          let mut state = state.write().unwrap();
+          if generation.is_some_and(|g| g != state.generation) {
+            return;
+          }
+          state.n = 0;
+          let prev =
+            std::mem::replace(&mut state.sbj, subjects::Subject::new());
+          (prev, state.sbj.clone())
+        };
+        prev.complete();
+        sctl.sink_next(sbj.observable());
+      }
+
+      fn arm<'a, Scheduler, Item>(
+        dur: Duration,
+        scheduler: &Scheduler,
+        state: Arc<RwLock<WindowState<'a, Item>>>,
+        sctl: StreamController<'a, Observable<'a, Item>>,
+      ) -> Subscription<'a>
+      where
+        Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+        Item: Clone + Send + Sync,
+      {
+        let generation = state.read().unwrap().generation;
+        scheduler.schedule_periodic(dur, move || {
+          next_window(&state, &sctl, Some(generation));
+        })
+      }
+
+      let sctl = StreamController::new(s);
+      let scheduler = scheduler_ctor.call(());
+      {
+        let scheduler = scheduler.clone();
+        sctl.set_on_finalize(move || {
+          scheduler.abort();
+        });
+      }
+
+      let state = Arc::new(RwLock::new(WindowState {
+        sbj: subjects::Subject::new(),
+        n: 0,
+        generation: 0,
+      }));
+
+      // the first window opens right away.
+      sctl.sink_next(state.read().unwrap().sbj.observable());
+
+      let timer = Arc::new(RwLock::new(Some(arm(
+        dur,
+        &scheduler,
+        Arc::clone(&state),
+        sctl.clone(),
+      ))));
+
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+      let state_next = Arc::clone(&state);
+      let state_error = Arc::clone(&state);
+      let state_complete = Arc::clone(&state);
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          let (sbj, full) = {
+            let mut state = state_next.write().unwrap();
+            state.n += 1;
+            let full = state.n >= count;
+            if full {
+              state.generation += 1;
+            }
+            (state.sbj.clone(), full)
+          };
+          sbj.next(x);
+          if full {
+            // the period restarts after a window is closed by count.
+            if let Some(t) = timer.write().unwrap().take() {
+              t.unsubscribe();
+            }
+            next_window(&state_next, &sctl_next, None);
+            let t = arm(
+              dur,
+              &scheduler,
+              Arc::clone(&state_next),
+              sctl_next.clone(),
+            );
+            *timer.write().unwrap() = Some(t);
+          }
+        },
+        move |_, e| {
+          let sbj = state_error.read().unwrap().sbj.clone();
+          sbj.error(e.clone());
+          sctl_error.sink_error(e);
+        },
+        move |serial| {
+          let sbj = state_complete.read().unwrap().sbj.clone();
+          sbj.complete();
+          sctl_complete.sink_complete(&serial);
+        },
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn window_with_time_or_count<Scheduler, SchedulerCreator>(
+    &self,
+    dur: Duration,
+    count: usize,
+    scheduler_ctor: SchedulerCreator,
+  ) -> Observable<'a, Observable<'a, Item>>
+  where
+    Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+    SchedulerCreator: Fn() -> Scheduler + Send + Sync + 'a,
+  {
+    WindowWithTimeOrCount::new(dur, count, scheduler_ctor).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .window_with_time_or_count(
+          time::Duration::from_millis(100),
+          3,
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| {
+            let log = Arc::clone(&log);
+            let items = Arc::new(RwLock::new(Vec::new()));
+            let items_complete = Arc::clone(&items);
+            x.subscribe(
+              move |y| items.write().unwrap().push(y),
+              junk_error!(),
+              move || {
+                log
+                  .write()
+                  .unwrap()
+                  .push(items_complete.read().unwrap().clone())
+              },
+            );
+          },
+          junk_error!(),
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    ts.advance_by(time::Duration::from_millis(50));
+    sbj.next(1);
+    sbj.next(2);
+    sbj.next(3);
+    // the count close at 50ms restarts the period.
+    ts.advance_by(time::Duration::from_millis(60));
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1, 2]]
+    );
+    ts.advance_by(time::Duration::from_millis(40));
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1, 2], vec![3]]
+    );
+
+    sbj.next(4);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0, 1, 2], vec![3], vec![4]]
+    );
+  }
+
+  #[test]
+  fn thread() {
+    observables::interval(
+      time::Duration::from_millis(30),
+      schedulers::new_thread_scheduler(),
+    )
+    .take(10)
+    .window_with_time_or_count(
+      time::Duration::from_millis(100),
+      2,
+      schedulers::new_thread_scheduler(),
+    )
+    .flat_map(|x| x.count())
+    .subscribe(
+      print_next_fmt!("{:?}"),
+      print_error!(),
+      print_complete!(),
+    );
+    thread::sleep(time::Duration::from_millis(500));
+  }
+}
@@ -0,0 +1,149 @@
+use crate::internals::{function_wrapper::*, stream_controller::*};
+use crate::prelude::*;
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone)]
+pub struct WithLatestFrom<'a, Item, Out>
+where
+  Item: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  observables: Vec<Observable<'a, Item>>,
+  combine_f: FunctionWrapper<'a, Vec<Item>, Out>,
+}
+
+impl<'a, Item, Out> WithLatestFrom<'a, Item, Out>
+where
+  Item: Clone + Send + Sync,
+  Out: Clone + Send + Sync,
+{
+  pub fn new<F>(
+    observables: &[Observable<'a, Item>],
+    f: F,
+  ) -> WithLatestFrom<'a, Item, Out>
+  where
+    F: Fn(Vec<Item>) -> Out + Send + Sync + 'a,
+  {
+    WithLatestFrom {
+      observables: observables.to_vec(),
+      combine_f: FunctionWrapper::new(f),
+    }
+  }
+  pub fn execute(&self, source: Observable<'a, Item>) -> Observable<'a, Out> {
+    let observables = self.observables.clone();
+    let combine_f = self.combine_f.clone();
+
+    Observable::create(move |s| {
+      let combine_f = combine_f.clone();
+      let latest = Arc::new(RwLock::new(vec![
+        None::<Item>;
+        observables.len()
+      ]));
+
+      let sctl = StreamController::new(s);
+
+      // the other observables only update their latest value.
+      observables.iter().enumerate().for_each(|(idx, o)| {
+        let latest = Arc::clone(&latest);
+        let sctl_error = sctl.clone();
+        let sctl_complete = sctl.clone();
+        o.inner_subscribe(sctl.new_observer(
+          move |_, x| {
+            latest.write().unwrap()[idx] = Some(x);
+          },
+          move |_, e| {
+            sctl_error.sink_error(e);
+          },
+          move |serial| {
+            sctl_complete.upstream_abort_observe(&serial);
+          },
+        ));
+      });
+
+      let sctl_next = sctl.clone();
+      let sctl_error = sctl.clone();
+      let sctl_complete = sctl.clone();
+
+      source.inner_subscribe(sctl.new_observer(
+        move |_, x| {
+          // nothing is emitted until every other observable has a value.
+          let values = {
+            let latest = latest.read().unwrap();
+            latest.iter().cloned().collect::<Option<Vec<_>>>()
+          };
+          if let Some(values) = values {
+            let mut v = Vec::with_capacity(values.len() + 1);
+            v.push(x);
+            v.extend(values);
+            sctl_next.sink_next(combine_f.call(v));
+          }
+        },
+        move |_, e| {
+          sctl_error.sink_error(e);
+        },
+        move |_| sctl_complete.sink_complete_force(), // others also unsubscribe
+      ));
+    })
+  }
+}
+
+impl<'a, Item> Observable<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  pub fn with_latest_from<Out, F>(
+    &self,
+    observables: &[Observable<'a, Item>],
+    f: F,
+  ) -> Observable<'a, Out>
+  where
+    Out: Clone + Send + Sync,
+    F: Fn(Vec<Item>) -> Out + Send + Sync + 'a,
+  {
+    WithLatestFrom::new(observables, f).execute(self.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let sbj = subjects::Subject::new();
+    let others = [subjects::Subject::new(), subjects::Subject::new()];
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .with_latest_from(
+          &[others[0].observable(), others[1].observable()],
+          |v| v,
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+
+    sbj.next(0);
+    others[0].next(10);
+    sbj.next(1);
+    others[1].next(20);
+    // the others do not trigger an emission.
+    others[1].next(21);
+    sbj.next(2);
+    others[0].next(11);
+    others[0].complete();
+    sbj.next(3);
+    sbj.complete();
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![2, 10, 21], vec![3, 11, 21]]
+    );
+  }
+}
@@ -1,15 +1,33 @@
 pub mod async_function_queue;
+pub mod current_thread_scheduler;
 pub mod default_scheduler;
 pub mod scheduler;
+pub mod test_scheduler;
 
 #[cfg(not(feature = "web"))]
 pub mod new_thread_scheduler;
+#[cfg(not(feature = "web"))]
+pub mod thread_pool_scheduler;
+#[cfg(not(feature = "web"))]
+pub mod timer_scheduler;
+
+#[cfg(feature = "tokio")]
+pub mod tokio_scheduler;
 
 pub mod schedulers {
   pub use crate::schedulers::async_function_queue::*;
+  pub use crate::schedulers::current_thread_scheduler::*;
   pub use crate::schedulers::default_scheduler::*;
   pub use crate::schedulers::scheduler::*;
+  pub use crate::schedulers::test_scheduler::*;
 
   #[cfg(not(feature = "web"))]
   pub use crate::schedulers::new_thread_scheduler::*;
+  #[cfg(not(feature = "web"))]
+  pub use crate::schedulers::thread_pool_scheduler::*;
+  #[cfg(not(feature = "web"))]
+  pub use crate::schedulers::timer_scheduler::*;
+
+  #[cfg(feature = "tokio")]
+  pub use crate::schedulers::tokio_scheduler::*;
 }
@@ -1,11 +1,18 @@
 use crate::internals::function_wrapper::FunctionWrapper;
 use std::{
-  collections::VecDeque,
+  collections::{BTreeMap, VecDeque},
   sync::{Arc, Condvar, Mutex, RwLock},
+  time::Instant,
 };
 
+struct AsyncFunctionQueueItems<'a> {
+  queue: VecDeque<FunctionWrapper<'a, (), ()>>,
+  timers: BTreeMap<(Instant, u64), FunctionWrapper<'a, (), ()>>,
+  serial: u64,
+}
+
 struct AsyncFunctionQueueData<'a> {
-  queue: Mutex<VecDeque<FunctionWrapper<'a, (), ()>>>,
+  items: Mutex<AsyncFunctionQueueItems<'a>>,
   cond: Condvar,
   abort: RwLock<bool>,
 }
@@ -18,7 +25,11 @@ impl<'a> AsyncFunctionQueue<'a> {
   pub fn new() -> AsyncFunctionQueue<'a> {
     AsyncFunctionQueue {
       data: Arc::new(AsyncFunctionQueueData {
-        queue: Mutex::new(VecDeque::new()),
+        items: Mutex::new(AsyncFunctionQueueItems {
+          queue: VecDeque::new(),
+          timers: BTreeMap::new(),
+          serial: 0,
+        }),
         cond: Condvar::new(),
         abort: RwLock::new(false),
       }),
@@ -28,22 +39,26 @@ impl<'a> AsyncFunctionQueue<'a> {
   pub fn scheduling(&self) {
     loop {
       let f = {
-        let que_mtx = self.data.queue.lock().unwrap();
-        let mut que_mtx = self
-          .data
-          .cond
-          .wait_while(que_mtx, |que| {
-            if *self.data.abort.read().unwrap() {
-              false
-            } else {
-              que.is_empty()
+        let mut items = self.data.items.lock().unwrap();
+        loop {
+          if *self.data.abort.read().unwrap() {
+            break None;
+          }
+          let now = Instant::now();
+          let due = items.timers.keys().next().cloned();
+          if let Some(due) = due {
+            if due.0 <= now {
+              break items.timers.remove(&due);
             }
-          })
-          .unwrap();
-        if *self.data.abort.read().unwrap() {
-          None
-        } else {
-          que_mtx.pop_front()
+          }
+          if let Some(f) = items.queue.pop_front() {
+            break Some(f);
+          }
+          items = if let Some(due) = due {
+            self.data.cond.wait_timeout(items, due.0 - now).unwrap().0
+          } else {
+            self.data.cond.wait(items).unwrap()
+          };
         }
       };
       if let Some(f) = f {
@@ -58,14 +73,41 @@ impl<'a> AsyncFunctionQueue<'a> {
   where
     F: Fn() + Send + Sync + 'a,
   {
-    let mut que_mtx = self.data.queue.lock().unwrap();
-    que_mtx.push_back(FunctionWrapper::new(move |_| f()));
+    if *self.data.abort.read().unwrap() {
+      return;
+    }
+    let mut items = self.data.items.lock().unwrap();
+    items.queue.push_back(FunctionWrapper::new(move |_| f()));
     self.data.cond.notify_one();
   }
 
+  pub fn post_at<F>(&self, at: Instant, f: F) -> (Instant, u64)
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    let mut items = self.data.items.lock().unwrap();
+    let key = (at, items.serial);
+    items.serial += 1;
+    if *self.data.abort.read().unwrap() {
+      return key;
+    }
+    items.timers.insert(key, FunctionWrapper::new(move |_| f()));
+    self.data.cond.notify_one();
+    key
+  }
+
+  pub fn cancel(&self, key: &(Instant, u64)) {
+    self.data.items.lock().unwrap().timers.remove(key);
+  }
+
+  pub fn is_pending(&self, key: &(Instant, u64)) -> bool {
+    self.data.items.lock().unwrap().timers.contains_key(key)
+  }
+
   pub fn stop(&self) {
-    let mut que_mtx = self.data.queue.lock().unwrap();
-    que_mtx.clear();
+    let mut items = self.data.items.lock().unwrap();
+    items.queue.clear();
+    items.timers.clear();
     *self.data.abort.write().unwrap() = true;
     self.data.cond.notify_one();
   }
@@ -74,7 +116,10 @@ impl<'a> AsyncFunctionQueue<'a> {
 #[cfg(test)]
 mod test {
   use super::AsyncFunctionQueue;
-  use std::{thread, time};
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
 
   #[test]
   fn basic() {
@@ -107,4 +152,39 @@ mod test {
     scheduler.stop();
     thread::sleep(time::Duration::from_millis(2000));
   }
+
+  #[test]
+  fn post_at() {
+    let scheduler = AsyncFunctionQueue::new();
+    let scheduler_thread = scheduler.clone();
+    thread::spawn(move || {
+      scheduler_thread.scheduling();
+    });
+
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let now = time::Instant::now();
+    let push = |n| {
+      let log = Arc::clone(&log);
+      move || log.write().unwrap().push(n)
+    };
+    scheduler.post_at(
+      now + time::Duration::from_millis(200),
+      push(3),
+    );
+    let key = scheduler.post_at(
+      now + time::Duration::from_millis(100),
+      push(2),
+    );
+    scheduler.post_at(
+      now + time::Duration::from_millis(50),
+      push(1),
+    );
+    scheduler.post(push(0));
+    scheduler.cancel(&key);
+    assert!(!scheduler.is_pending(&key));
+
+    thread::sleep(time::Duration::from_millis(400));
+    assert_eq!(*log.read().unwrap(), vec![0, 1, 3]);
+    scheduler.stop();
+  }
 }
@@ -0,0 +1,308 @@
+use super::scheduler::{IScheduler, ITimedScheduler};
+use crate::internals::function_wrapper::FunctionWrapper;
+use crate::prelude::*;
+use std::{
+  collections::{BTreeMap, HashMap, VecDeque},
+  sync::{Arc, RwLock},
+  thread::{self, ThreadId},
+  time::{Duration, Instant},
+};
+
+struct Trampoline<'a> {
+  queue: VecDeque<FunctionWrapper<'a, (), ()>>,
+  timers: BTreeMap<(Instant, u64), FunctionWrapper<'a, (), ()>>,
+}
+
+enum TrampolineStep<'a> {
+  Run(FunctionWrapper<'a, (), ()>),
+  Wait(Duration),
+  Done,
+}
+
+struct CurrentThreadSchedulerData<'a> {
+  trampolines: HashMap<ThreadId, Trampoline<'a>>,
+  serial: u64,
+  aborted: bool,
+}
+
+#[derive(Clone)]
+pub struct CurrentThreadScheduler<'a> {
+  data: Arc<RwLock<CurrentThreadSchedulerData<'a>>>,
+}
+
+impl<'a> CurrentThreadScheduler<'a> {
+  pub fn new() -> CurrentThreadScheduler<'a> {
+    CurrentThreadScheduler {
+      data: Arc::new(RwLock::new(
+        CurrentThreadSchedulerData {
+          trampolines: HashMap::new(),
+          serial: 0,
+          aborted: false,
+        },
+      )),
+    }
+  }
+
+  // The first call on a thread drains the trampoline of that thread.
+  // Calls made while draining only enqueue, so the stack never grows.
+  fn enqueue<F>(&self, f: F)
+  where
+    F: FnOnce(&mut Trampoline<'a>, u64),
+  {
+    let thread_id = thread::current().id();
+    let drain = {
+      let mut data = self.data.write().unwrap();
+      if data.aborted {
+        return;
+      }
+      let serial = data.serial;
+      data.serial += 1;
+      let drain = !data.trampolines.contains_key(&thread_id);
+      f(
+        data
+          .trampolines
+          .entry(thread_id)
+          .or_insert_with(|| Trampoline {
+            queue: VecDeque::new(),
+            timers: BTreeMap::new(),
+          }),
+        serial,
+      );
+      drain
+    };
+    if drain {
+      self.drain(thread_id);
+    }
+  }
+
+  fn drain(&self, thread_id: ThreadId) {
+    loop {
+      let step = {
+        let mut data = self.data.write().unwrap();
+        if let Some(t) = data.trampolines.get_mut(&thread_id) {
+          let due = t.timers.keys().next().cloned();
+          if let Some(due) = due {
+            let now = Instant::now();
+            if due.0 <= now {
+              TrampolineStep::Run(t.timers.remove(&due).unwrap())
+            } else if let Some(f) = t.queue.pop_front() {
+              TrampolineStep::Run(f)
+            } else {
+              TrampolineStep::Wait(due.0 - now)
+            }
+          } else if let Some(f) = t.queue.pop_front() {
+            TrampolineStep::Run(f)
+          } else {
+            data.trampolines.remove(&thread_id);
+            TrampolineStep::Done
+          }
+        } else {
+          TrampolineStep::Done
+        }
+      };
+      match step {
+        TrampolineStep::Run(f) => f.call(()),
+        TrampolineStep::Wait(dur) => thread::sleep(dur),
+        TrampolineStep::Done => break,
+      }
+    }
+  }
+}
+
+impl<'a> Default for CurrentThreadScheduler<'a> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<'a> IScheduler<'a> for CurrentThreadScheduler<'a> {
+  fn post<F>(&self, f: F)
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    self.enqueue(move |t, _| {
+      t.queue.push_back(FunctionWrapper::new(move |_| f()));
+    });
+  }
+  fn abort(&self) {
+    let mut data = self.data.write().unwrap();
+    data.aborted = true;
+    data.trampolines.values_mut().for_each(|t| {
+      t.queue.clear();
+      t.timers.clear();
+    });
+  }
+  fn defers_on_current_thread(&self) -> bool {
+    true
+  }
+}
+
+impl<'a> ITimedScheduler<'a> for CurrentThreadScheduler<'a> {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'a>
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    let thread_id = thread::current().id();
+    let key = Arc::new(RwLock::new(None));
+    {
+      let key = Arc::clone(&key);
+      let at = Instant::now() + dur;
+      self.enqueue(move |t, serial| {
+        *key.write().unwrap() = Some((at, serial));
+        t.timers.insert(
+          (at, serial),
+          FunctionWrapper::new(move |_| f()),
+        );
+      });
+    }
+
+    let data_unsub = Arc::clone(&self.data);
+    let data_issub = Arc::clone(&self.data);
+    let key_issub = Arc::clone(&key);
+    Subscription::new(
+      move || {
+        if let Some(key) = &*key.read().unwrap() {
+          let mut data = data_unsub.write().unwrap();
+          if let Some(t) = data.trampolines.get_mut(&thread_id) {
+            t.timers.remove(key);
+          }
+        }
+      },
+      move || {
+        if let Some(key) = &*key_issub.read().unwrap() {
+          let data = data_issub.read().unwrap();
+          if let Some(t) = data.trampolines.get(&thread_id) {
+            return t.timers.contains_key(key);
+          }
+        }
+        false
+      },
+    )
+  }
+}
+
+pub fn current_thread_scheduler<'a>() -> fn() -> CurrentThreadScheduler<'a> {
+  || CurrentThreadScheduler::new()
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use scheduler::IScheduler;
+  use std::sync::{Arc, RwLock};
+
+  #[test]
+  fn basic() {
+    let s = schedulers::current_thread_scheduler()();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let s_outer = s.clone();
+      let log = Arc::clone(&log);
+      s.post(move || {
+        log.write().unwrap().push("outer start");
+        {
+          let log = Arc::clone(&log);
+          s_outer.post(move || log.write().unwrap().push("inner"));
+        }
+        log.write().unwrap().push("outer end");
+      });
+    }
+
+    assert_eq!(
+      *log.read().unwrap(),
+      vec!["outer start", "outer end", "inner"]
+    );
+  }
+
+  #[test]
+  fn deep_recursion() {
+    fn countdown<'a>(
+      n: usize,
+      s: schedulers::CurrentThreadScheduler<'a>,
+      done: Arc<RwLock<bool>>,
+    ) {
+      if n == 0 {
+        *done.write().unwrap() = true;
+        return;
+      }
+      let s_next = s.clone();
+      s.post(move || countdown(n - 1, s_next.clone(), Arc::clone(&done)));
+    }
+
+    let done = Arc::new(RwLock::new(false));
+    countdown(
+      1_000_000,
+      schedulers::CurrentThreadScheduler::new(),
+      Arc::clone(&done),
+    );
+    assert!(*done.read().unwrap());
+  }
+
+  #[test]
+  fn nested_instance() {
+    let outer = schedulers::CurrentThreadScheduler::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      outer.post(move || {
+        let local = vec![0xab_u8; 4];
+        let local_ref = &local;
+        let inner = schedulers::CurrentThreadScheduler::new();
+        {
+          let log = Arc::clone(&log);
+          inner.post(move || log.write().unwrap().push(local_ref.clone()));
+        }
+        // each instance drains its own queue, so work borrowing `local` has
+        // run before `post` returns.
+        assert_eq!(log.read().unwrap().len(), 1);
+      });
+    }
+    assert_eq!(
+      *log.read().unwrap(),
+      vec![vec![0xab_u8; 4]]
+    );
+  }
+
+  #[test]
+  fn nested_operators() {
+    let o = (0..500).fold(observables::just(1), |o, n| {
+      if n % 2 == 0 {
+        o.repeat(1)
+      } else {
+        observables::empty().concat(&[o])
+      }
+    });
+
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log_next = Arc::clone(&log);
+      let log_complete = Arc::clone(&log);
+      o.subscribe(
+        move |x| log_next.write().unwrap().push(x),
+        print_error!(),
+        move || log_complete.write().unwrap().push(0),
+      );
+    }
+    assert_eq!(*log.read().unwrap(), vec![1, 0]);
+  }
+
+  #[test]
+  fn abort() {
+    let s = schedulers::current_thread_scheduler()();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let s_outer = s.clone();
+      let log = Arc::clone(&log);
+      s.post(move || {
+        let log_inner = Arc::clone(&log);
+        s_outer.post(move || log_inner.write().unwrap().push(1));
+        s_outer.abort();
+        log.write().unwrap().push(0);
+      });
+    }
+    assert_eq!(*log.read().unwrap(), vec![0]);
+  }
+}
@@ -1,22 +1,81 @@
-use super::scheduler::IScheduler;
+use super::scheduler::{IScheduler, ITimedScheduler};
+use crate::prelude::*;
+use std::{
+  sync::{Arc, OnceLock, RwLock},
+  time::Duration,
+};
+
+// `post` runs inline, but timed work is handed to the shared timer so that
+// `schedule_after` and `schedule_periodic` return without blocking.
+#[cfg(not(feature = "web"))]
+type Timer = schedulers::TimerScheduler;
+#[cfg(feature = "web")]
+type Timer = web::AsyncScheduler;
 
 #[derive(Clone)]
-pub struct DefaultScheduler {}
+pub struct DefaultScheduler {
+  aborted: Arc<RwLock<bool>>,
+  timer: Arc<OnceLock<Timer>>,
+}
 
 impl DefaultScheduler {
   pub fn new() -> DefaultScheduler {
-    DefaultScheduler {}
+    DefaultScheduler {
+      aborted: Arc::new(RwLock::new(false)),
+      timer: Arc::new(OnceLock::new()),
+    }
+  }
+
+  fn is_aborted(&self) -> bool {
+    *self.aborted.read().unwrap()
+  }
+
+  // the timer is only started by the first timed call.
+  fn timer(&self) -> &Timer {
+    self.timer.get_or_init(Timer::new)
   }
 }
 
-impl IScheduler<'_> for DefaultScheduler {
+impl<'a> IScheduler<'a> for DefaultScheduler {
   fn post<F>(&self, f: F)
   where
-    F: Fn() + Send + Sync,
+    F: Fn() + Clone + Send + Sync + 'a,
+  {
+    if !self.is_aborted() {
+      f();
+    }
+  }
+  fn abort(&self) {
+    *self.aborted.write().unwrap() = true;
+    if let Some(timer) = self.timer.get() {
+      timer.abort();
+    }
+  }
+}
+
+impl ITimedScheduler<'static> for DefaultScheduler {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'static>
+  where
+    F: Fn() + Clone + Send + Sync + 'static,
   {
-    f();
+    if self.is_aborted() {
+      return Subscription::new(|| {}, || false);
+    }
+    self.timer().schedule_after(dur, f)
+  }
+  fn schedule_periodic<F>(
+    &self,
+    period: Duration,
+    f: F,
+  ) -> Subscription<'static>
+  where
+    F: Fn() + Clone + Send + Sync + 'static,
+  {
+    if self.is_aborted() {
+      return Subscription::new(|| {}, || false);
+    }
+    self.timer().schedule_periodic(period, f)
   }
-  fn abort(&self) {}
 }
 
 pub fn default_scheduler() -> fn() -> DefaultScheduler {
@@ -26,8 +85,11 @@ pub fn default_scheduler() -> fn() -> DefaultScheduler {
 #[cfg(test)]
 mod test {
   use crate::prelude::*;
-  use scheduler::IScheduler;
-  use std::{thread, time};
+  use scheduler::{IScheduler, ITimedScheduler};
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
 
   #[test]
   fn basic() {
@@ -53,4 +115,84 @@ mod test {
       });
     }
   }
+
+  #[test]
+  fn borrowed() {
+    let local = vec![1, 2, 3];
+    let r = &local;
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::from_iter(r.iter().cloned())
+        .map(move |x| x + r[0])
+        .observe_on(schedulers::default_scheduler())
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          print_error!(),
+          print_complete!(),
+        );
+    }
+    assert_eq!(*log.read().unwrap(), vec![2, 3, 4]);
+  }
+
+  #[test]
+  fn lazy_timer() {
+    let s = schedulers::default_scheduler()();
+    s.post(|| {});
+    assert!(s.timer.get().is_none());
+
+    s.schedule_after(time::Duration::ZERO, || {});
+    assert!(s.timer.get().is_some());
+  }
+
+  #[test]
+  fn interval() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    {
+      let log = Arc::clone(&log);
+      observables::interval(
+        time::Duration::from_millis(100),
+        schedulers::default_scheduler(),
+      )
+      .take(3)
+      .subscribe(
+        move |x| log.write().unwrap().push(x),
+        print_error!(),
+        print_complete!(),
+      );
+    }
+    // subscribe returns before the first tick.
+    assert!(log.read().unwrap().is_empty());
+
+    thread::sleep(time::Duration::from_millis(500));
+    assert_eq!(*log.read().unwrap(), vec![0, 1, 2]);
+  }
+
+  #[test]
+  fn window_with_time() {
+    let windows = Arc::new(RwLock::new(0));
+    let sbj = subjects::Subject::<i32>::new();
+
+    let sbsc = {
+      let windows = Arc::clone(&windows);
+      sbj
+        .observable()
+        .window_with_time(
+          time::Duration::from_millis(100),
+          schedulers::default_scheduler(),
+        )
+        .subscribe(
+          move |_| *windows.write().unwrap() += 1,
+          print_error!(),
+          print_complete!(),
+        )
+    };
+    // the source is subscribed once subscribe returns.
+    sbj.next(1);
+    assert_eq!(*windows.read().unwrap(), 1);
+
+    thread::sleep(time::Duration::from_millis(350));
+    sbsc.unsubscribe();
+    assert!(*windows.read().unwrap() >= 3);
+  }
 }
@@ -1,5 +1,9 @@
-use super::schedulers::{AsyncFunctionQueue, IScheduler};
-use std::thread;
+use super::schedulers::{AsyncFunctionQueue, IScheduler, ITimedScheduler};
+use crate::prelude::*;
+use std::{
+  thread,
+  time::{Duration, Instant},
+};
 
 #[derive(Clone)]
 pub struct NewThreadScheduler<'a> {
@@ -31,6 +35,21 @@ impl IScheduler<'static> for NewThreadScheduler<'static> {
   }
 }
 
+impl ITimedScheduler<'static> for NewThreadScheduler<'static> {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'static>
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    let key = self.scheduler.post_at(Instant::now() + dur, f);
+    let scheduler_unsub = self.scheduler.clone();
+    let scheduler_issub = self.scheduler.clone();
+    Subscription::new(
+      move || scheduler_unsub.cancel(&key),
+      move || scheduler_issub.is_pending(&key),
+    )
+  }
+}
+
 pub fn new_thread_scheduler<'a>() -> fn() -> NewThreadScheduler<'static> {
   || NewThreadScheduler::new()
 }
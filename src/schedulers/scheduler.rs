@@ -1,6 +1,80 @@
+use crate::prelude::*;
+use std::{
+  sync::{Arc, RwLock},
+  time::Duration,
+};
+
 pub trait IScheduler<'a> {
   fn post<F>(&self, f: F)
   where
     F: Fn() + Clone + Send + Sync + 'a;
   fn abort(&self);
+  // true when posted work may wait until the posting thread returns, so the
+  // poster must never block waiting for it.
+  fn defers_on_current_thread(&self) -> bool {
+    false
+  }
+}
+
+pub trait ITimedScheduler<'a>: IScheduler<'a> {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'a>
+  where
+    F: Fn() + Clone + Send + Sync + 'a;
+  fn schedule_periodic<F>(&self, period: Duration, f: F) -> Subscription<'a>
+  where
+    F: Fn() + Clone + Send + Sync + 'a,
+    Self: Clone + Send + Sync + 'a,
+  {
+    fn arm<'a, Scheduler, F>(
+      scheduler: Scheduler,
+      period: Duration,
+      f: F,
+      current: Arc<RwLock<Option<Subscription<'a>>>>,
+      cancelled: Arc<RwLock<bool>>,
+    ) where
+      Scheduler: ITimedScheduler<'a> + Clone + Send + Sync + 'a,
+      F: Fn() + Clone + Send + Sync + 'a,
+    {
+      let sbsc = {
+        let scheduler = scheduler.clone();
+        let current = Arc::clone(&current);
+        let cancelled = Arc::clone(&cancelled);
+        scheduler.clone().schedule_after(period, move || {
+          if *cancelled.read().unwrap() {
+            return;
+          }
+          f();
+          arm(
+            scheduler.clone(),
+            period,
+            f.clone(),
+            Arc::clone(&current),
+            Arc::clone(&cancelled),
+          );
+        })
+      };
+      *current.write().unwrap() = Some(sbsc);
+    }
+
+    let current = Arc::new(RwLock::new(None::<Subscription<'a>>));
+    let cancelled = Arc::new(RwLock::new(false));
+    arm(
+      self.clone(),
+      period,
+      f,
+      Arc::clone(&current),
+      Arc::clone(&cancelled),
+    );
+
+    let cancelled_issub = Arc::clone(&cancelled);
+    Subscription::new(
+      move || {
+        *cancelled.write().unwrap() = true;
+        if let Some(sbsc) = current.write().unwrap().take() {
+          sbsc.unsubscribe();
+        }
+      },
+      move || !*cancelled_issub.read().unwrap(),
+    )
+  }
 }
@@ -0,0 +1,392 @@
+use super::scheduler::{IScheduler, ITimedScheduler};
+use crate::internals::function_wrapper::FunctionWrapper;
+use crate::prelude::*;
+use std::{
+  collections::BTreeMap,
+  sync::{Arc, RwLock},
+  time::Duration,
+};
+
+struct TestSchedulerTask<'a> {
+  aborted: Arc<RwLock<bool>>,
+  f: FunctionWrapper<'a, (), ()>,
+}
+
+struct TestSchedulerClock<'a> {
+  now: Duration,
+  serial: u64,
+  tasks: BTreeMap<(Duration, u64), TestSchedulerTask<'a>>,
+}
+
+#[derive(Clone)]
+pub struct TestScheduler<'a> {
+  clock: Arc<RwLock<TestSchedulerClock<'a>>>,
+  aborted: Arc<RwLock<bool>>,
+}
+
+impl<'a> TestScheduler<'a> {
+  pub fn new() -> TestScheduler<'a> {
+    TestScheduler {
+      clock: Arc::new(RwLock::new(TestSchedulerClock {
+        now: Duration::ZERO,
+        serial: 0,
+        tasks: BTreeMap::new(),
+      })),
+      aborted: Arc::new(RwLock::new(false)),
+    }
+  }
+
+  // Each call of the returned creator yields a scheduler that shares the
+  // virtual clock but can be aborted independently.
+  pub fn scheduler_ctor(
+    &self,
+  ) -> impl Fn() -> TestScheduler<'a> + Clone + Send + Sync + 'a {
+    let clock = Arc::clone(&self.clock);
+    move || TestScheduler {
+      clock: Arc::clone(&clock),
+      aborted: Arc::new(RwLock::new(false)),
+    }
+  }
+
+  pub fn now(&self) -> Duration {
+    self.clock.read().unwrap().now
+  }
+
+  pub fn advance_by(&self, dur: Duration) {
+    self.advance_to(self.now() + dur);
+  }
+
+  pub fn advance_to(&self, time: Duration) {
+    while let Some(task) = self.pop_task(Some(time)) {
+      if !*task.aborted.read().unwrap() {
+        task.f.call(());
+      }
+    }
+    let mut clock = self.clock.write().unwrap();
+    if clock.now < time {
+      clock.now = time;
+    }
+  }
+
+  // Never returns while a periodic source such as `interval` is alive.
+  pub fn run_until_idle(&self) {
+    while let Some(task) = self.pop_task(None) {
+      if !*task.aborted.read().unwrap() {
+        task.f.call(());
+      }
+    }
+  }
+
+  fn schedule_at<F>(&self, due: Duration, f: F) -> (Duration, u64)
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    let mut clock = self.clock.write().unwrap();
+    let key = (due, clock.serial);
+    clock.serial += 1;
+    if *self.aborted.read().unwrap() {
+      return key;
+    }
+    clock.tasks.insert(
+      key,
+      TestSchedulerTask {
+        aborted: Arc::clone(&self.aborted),
+        f: FunctionWrapper::new(move |_| f()),
+      },
+    );
+    key
+  }
+
+  fn pop_task(&self, until: Option<Duration>) -> Option<TestSchedulerTask<'a>> {
+    let mut clock = self.clock.write().unwrap();
+    let due = clock.tasks.keys().next().cloned()?;
+    if let Some(until) = until {
+      if due.0 > until {
+        return None;
+      }
+    }
+    if clock.now < due.0 {
+      clock.now = due.0;
+    }
+    clock.tasks.remove(&due)
+  }
+}
+
+impl<'a> Default for TestScheduler<'a> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<'a> IScheduler<'a> for TestScheduler<'a> {
+  fn post<F>(&self, f: F)
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    self.schedule_at(self.now(), f);
+  }
+  fn abort(&self) {
+    *self.aborted.write().unwrap() = true;
+    self
+      .clock
+      .write()
+      .unwrap()
+      .tasks
+      .retain(|_, task| !Arc::ptr_eq(&task.aborted, &self.aborted));
+  }
+  fn defers_on_current_thread(&self) -> bool {
+    true
+  }
+}
+
+impl<'a> ITimedScheduler<'a> for TestScheduler<'a> {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'a>
+  where
+    F: Fn() + Send + Sync + 'a,
+  {
+    let key = self.schedule_at(self.now() + dur, f);
+    let clock_unsub = Arc::clone(&self.clock);
+    let clock_issub = Arc::clone(&self.clock);
+    Subscription::new(
+      move || {
+        clock_unsub.write().unwrap().tasks.remove(&key);
+      },
+      move || clock_issub.read().unwrap().tasks.contains_key(&key),
+    )
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use scheduler::{IScheduler, ITimedScheduler};
+  use std::{
+    sync::{Arc, RwLock},
+    time,
+  };
+
+  #[test]
+  fn basic() {
+    let ts = schedulers::TestScheduler::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      ts.schedule_after(
+        time::Duration::from_secs(2),
+        move || {
+          log.write().unwrap().push(2);
+        },
+      );
+    }
+    {
+      let log = Arc::clone(&log);
+      ts.schedule_after(
+        time::Duration::from_secs(1),
+        move || {
+          log.write().unwrap().push(1);
+        },
+      );
+    }
+    {
+      let log = Arc::clone(&log);
+      ts.post(move || {
+        log.write().unwrap().push(0);
+      });
+    }
+
+    assert_eq!(*log.read().unwrap(), Vec::<i32>::new());
+    ts.advance_by(time::Duration::ZERO);
+    assert_eq!(*log.read().unwrap(), vec![0]);
+    ts.advance_by(time::Duration::from_millis(1500));
+    assert_eq!(*log.read().unwrap(), vec![0, 1]);
+    assert_eq!(
+      ts.now(),
+      time::Duration::from_millis(1500)
+    );
+    ts.run_until_idle();
+    assert_eq!(*log.read().unwrap(), vec![0, 1, 2]);
+    assert_eq!(ts.now(), time::Duration::from_secs(2));
+  }
+
+  #[test]
+  fn abort() {
+    let ts = schedulers::TestScheduler::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    let s1 = ts.scheduler_ctor()();
+    let s2 = ts.scheduler_ctor()();
+    {
+      let log = Arc::clone(&log);
+      s1.schedule_after(
+        time::Duration::from_secs(1),
+        move || {
+          log.write().unwrap().push(1);
+        },
+      );
+    }
+    {
+      let log = Arc::clone(&log);
+      s2.schedule_after(
+        time::Duration::from_secs(1),
+        move || {
+          log.write().unwrap().push(2);
+        },
+      );
+    }
+    s1.abort();
+    ts.advance_to(time::Duration::from_secs(10));
+    assert_eq!(*log.read().unwrap(), vec![2]);
+  }
+
+  #[test]
+  fn cancel() {
+    let ts = schedulers::TestScheduler::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    let once = {
+      let log = Arc::clone(&log);
+      ts.schedule_after(
+        time::Duration::from_secs(1),
+        move || {
+          log.write().unwrap().push(0);
+        },
+      )
+    };
+    let periodic = {
+      let log = Arc::clone(&log);
+      ts.schedule_periodic(
+        time::Duration::from_secs(1),
+        move || {
+          log.write().unwrap().push(1);
+        },
+      )
+    };
+    assert!(once.is_subscribed());
+    once.unsubscribe();
+    assert!(!once.is_subscribed());
+
+    ts.advance_to(time::Duration::from_secs(3));
+    assert_eq!(*log.read().unwrap(), vec![1, 1, 1]);
+    periodic.unsubscribe();
+    ts.run_until_idle();
+    assert_eq!(*log.read().unwrap(), vec![1, 1, 1]);
+    assert_eq!(ts.now(), time::Duration::from_secs(3));
+  }
+
+  #[test]
+  fn interval() {
+    let ts = schedulers::TestScheduler::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      observables::interval(
+        time::Duration::from_millis(100),
+        ts.scheduler_ctor(),
+      )
+      .take(3)
+      .subscribe(
+        move |x| log.write().unwrap().push(x),
+        junk_error!(),
+        junk_complete!(),
+      );
+    }
+
+    ts.advance_by(time::Duration::from_millis(250));
+    assert_eq!(*log.read().unwrap(), vec![0, 1]);
+    ts.run_until_idle();
+    assert_eq!(*log.read().unwrap(), vec![0, 1, 2]);
+    assert_eq!(
+      ts.now(),
+      time::Duration::from_millis(300)
+    );
+  }
+
+  #[test]
+  fn long_debounce() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    let sbsc = {
+      let log = Arc::clone(&log);
+      sbj
+        .observable()
+        .debounce(
+          time::Duration::from_secs(600),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| log.write().unwrap().push(x),
+          junk_error!(),
+          junk_complete!(),
+        )
+    };
+
+    sbj.next(1);
+    ts.advance_by(time::Duration::from_secs(599));
+    assert_eq!(*log.read().unwrap(), Vec::<i32>::new());
+    ts.advance_by(time::Duration::from_secs(1));
+    assert_eq!(*log.read().unwrap(), vec![1]);
+    sbsc.unsubscribe();
+  }
+
+  #[test]
+  fn timeout() {
+    let ts = schedulers::TestScheduler::new();
+    let sbj = subjects::Subject::<i32>::new();
+    let timed_out = Arc::new(RwLock::new(false));
+
+    {
+      let timed_out = Arc::clone(&timed_out);
+      sbj
+        .observable()
+        .timeout(
+          time::Duration::from_millis(100),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          junk_next!(),
+          move |e| {
+            *timed_out.write().unwrap() = e.is::<std::io::Error>();
+          },
+          junk_complete!(),
+        );
+    }
+
+    sbj.next(1);
+    ts.advance_by(time::Duration::from_millis(99));
+    sbj.next(2);
+    ts.advance_by(time::Duration::from_millis(99));
+    assert!(!*timed_out.read().unwrap());
+    ts.advance_by(time::Duration::from_millis(1));
+    assert!(*timed_out.read().unwrap());
+  }
+
+  #[test]
+  fn delay() {
+    let ts = schedulers::TestScheduler::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log_next = Arc::clone(&log);
+      let log_complete = Arc::clone(&log);
+      observables::from_iter(0..3)
+        .delay(
+          time::Duration::from_secs(5),
+          ts.scheduler_ctor(),
+        )
+        .subscribe(
+          move |x| log_next.write().unwrap().push(x),
+          junk_error!(),
+          move || log_complete.write().unwrap().push(-1),
+        );
+    }
+
+    ts.advance_by(time::Duration::from_millis(4999));
+    assert_eq!(*log.read().unwrap(), Vec::<i32>::new());
+    ts.advance_by(time::Duration::from_millis(1));
+    assert_eq!(*log.read().unwrap(), vec![0, 1, 2, -1]);
+  }
+}
@@ -0,0 +1,197 @@
+use super::schedulers::{AsyncFunctionQueue, IScheduler, ITimedScheduler};
+use crate::internals::shared_function_queue::SharedFunctionQueue;
+use crate::prelude::*;
+use std::{
+  sync::{Arc, RwLock},
+  thread,
+  time::Duration,
+};
+
+struct ThreadPoolData {
+  workers: Vec<AsyncFunctionQueue<'static>>,
+  next: RwLock<usize>,
+}
+
+impl Drop for ThreadPoolData {
+  fn drop(&mut self) {
+    self.workers.iter().for_each(|w| w.stop());
+  }
+}
+
+#[derive(Clone)]
+pub struct ThreadPool {
+  data: Arc<ThreadPoolData>,
+}
+
+impl ThreadPool {
+  pub fn new(workers: usize) -> ThreadPool {
+    assert!(workers > 0);
+    ThreadPool {
+      data: Arc::new(ThreadPoolData {
+        workers: (0..workers)
+          .map(|_| {
+            let queue = AsyncFunctionQueue::new();
+            let queue_thread = queue.clone();
+            thread::spawn(move || {
+              queue_thread.scheduling();
+            });
+            queue
+          })
+          .collect(),
+        next: RwLock::new(0),
+      }),
+    }
+  }
+
+  pub fn workers(&self) -> usize {
+    self.data.workers.len()
+  }
+
+  // Schedulers are pinned to a single worker in turn, so everything posted
+  // through one scheduler keeps its order.
+  fn next_worker(&self) -> AsyncFunctionQueue<'static> {
+    let mut next = self.data.next.write().unwrap();
+    let worker = self.data.workers[*next].clone();
+    *next = (*next + 1) % self.data.workers.len();
+    worker
+  }
+}
+
+#[derive(Clone)]
+pub struct ThreadPoolScheduler {
+  queue: SharedFunctionQueue<'static>,
+  _pool: ThreadPool,
+}
+
+impl ThreadPoolScheduler {
+  pub fn new(pool: &ThreadPool) -> ThreadPoolScheduler {
+    ThreadPoolScheduler {
+      queue: SharedFunctionQueue::new(pool.next_worker()),
+      _pool: pool.clone(),
+    }
+  }
+}
+
+impl IScheduler<'static> for ThreadPoolScheduler {
+  fn post<F>(&self, f: F)
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    self.queue.post(f);
+  }
+  fn abort(&self) {
+    self.queue.abort();
+  }
+}
+
+impl ITimedScheduler<'static> for ThreadPoolScheduler {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'static>
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    self.queue.schedule_after(dur, f)
+  }
+}
+
+pub fn thread_pool_scheduler(
+  workers: usize,
+) -> impl Fn() -> ThreadPoolScheduler + Clone + Send + Sync {
+  let pool = ThreadPool::new(workers);
+  move || ThreadPoolScheduler::new(&pool)
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use scheduler::{IScheduler, ITimedScheduler};
+  use std::{
+    collections::HashSet,
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let pool = schedulers::ThreadPool::new(2);
+    let threads = Arc::new(RwLock::new(HashSet::new()));
+
+    (0..4).for_each(|n| {
+      let s = schedulers::ThreadPoolScheduler::new(&pool);
+      let threads = Arc::clone(&threads);
+      s.post(move || {
+        threads.write().unwrap().insert(thread::current().id());
+        println!("#{} start", n);
+        thread::sleep(time::Duration::from_millis(200));
+        println!("#{} end", n);
+      });
+    });
+
+    thread::sleep(time::Duration::from_millis(600));
+    assert_eq!(
+      threads.read().unwrap().len(),
+      pool.workers()
+    );
+  }
+
+  #[test]
+  fn ordering() {
+    let results = Arc::new(RwLock::new(Vec::new()));
+    let scheduler_ctor = schedulers::thread_pool_scheduler(4);
+
+    (0..4).for_each(|_| {
+      let items = Arc::new(RwLock::new(Vec::new()));
+      let items_complete = Arc::clone(&items);
+      let results = Arc::clone(&results);
+      observables::from_iter(0..100)
+        .observe_on(scheduler_ctor.clone())
+        .subscribe(
+          move |x| items.write().unwrap().push(x),
+          junk_error!(),
+          move || {
+            results
+              .write()
+              .unwrap()
+              .push(items_complete.read().unwrap().clone())
+          },
+        );
+    });
+
+    thread::sleep(time::Duration::from_millis(500));
+    let results = results.read().unwrap();
+    assert_eq!(results.len(), 4);
+    results.iter().for_each(|v| {
+      assert_eq!(*v, (0..100).collect::<Vec<_>>());
+    });
+  }
+
+  #[test]
+  fn zero_delay() {
+    let pool = schedulers::ThreadPool::new(2);
+    let s = schedulers::ThreadPoolScheduler::new(&pool);
+    let fired = Arc::new(RwLock::new(0));
+
+    (0..1000).for_each(|_| {
+      let fired = Arc::clone(&fired);
+      s.schedule_after(time::Duration::ZERO, move || {
+        *fired.write().unwrap() += 1;
+      });
+    });
+
+    thread::sleep(time::Duration::from_millis(200));
+    assert_eq!(*fired.read().unwrap(), 1000);
+    assert_eq!(s.queue.pending_timers(), 0);
+  }
+
+  #[test]
+  fn subscribe_on() {
+    observables::from_iter(0..5)
+      .subscribe_on(schedulers::thread_pool_scheduler(2))
+      .observe_on(schedulers::thread_pool_scheduler(2))
+      .subscribe(
+        print_next_fmt!("{}"),
+        print_error!(),
+        print_complete!(),
+      );
+    thread::sleep(time::Duration::from_millis(500));
+  }
+}
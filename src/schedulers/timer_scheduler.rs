@@ -0,0 +1,182 @@
+use super::schedulers::{AsyncFunctionQueue, IScheduler, ITimedScheduler};
+use crate::internals::shared_function_queue::SharedFunctionQueue;
+use crate::prelude::*;
+use std::{sync::OnceLock, thread, time::Duration};
+
+// All `TimerScheduler`s share this queue and its single thread, so callbacks
+// should hand heavy work over to another scheduler.
+fn shared_queue() -> AsyncFunctionQueue<'static> {
+  static QUEUE: OnceLock<AsyncFunctionQueue<'static>> = OnceLock::new();
+  QUEUE
+    .get_or_init(|| {
+      let queue = AsyncFunctionQueue::new();
+      let queue_thread = queue.clone();
+      thread::spawn(move || {
+        queue_thread.scheduling();
+      });
+      queue
+    })
+    .clone()
+}
+
+#[derive(Clone)]
+pub struct TimerScheduler {
+  queue: SharedFunctionQueue<'static>,
+}
+
+impl TimerScheduler {
+  pub fn new() -> TimerScheduler {
+    TimerScheduler {
+      queue: SharedFunctionQueue::new(shared_queue()),
+    }
+  }
+}
+
+impl Default for TimerScheduler {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl IScheduler<'static> for TimerScheduler {
+  fn post<F>(&self, f: F)
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    self.queue.post(f);
+  }
+  fn abort(&self) {
+    self.queue.abort();
+  }
+}
+
+impl ITimedScheduler<'static> for TimerScheduler {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'static>
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    self.queue.schedule_after(dur, f)
+  }
+}
+
+pub fn timer_scheduler() -> fn() -> TimerScheduler {
+  || TimerScheduler::new()
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use scheduler::{IScheduler, ITimedScheduler};
+  use std::{
+    sync::{Arc, RwLock},
+    thread, time,
+  };
+
+  #[test]
+  fn basic() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let s = schedulers::timer_scheduler()();
+
+    {
+      let log = Arc::clone(&log);
+      s.schedule_after(
+        time::Duration::from_millis(200),
+        move || {
+          log.write().unwrap().push(2);
+        },
+      );
+    }
+    let cancelled = {
+      let log = Arc::clone(&log);
+      s.schedule_after(
+        time::Duration::from_millis(100),
+        move || {
+          log.write().unwrap().push(1);
+        },
+      )
+    };
+    {
+      let log = Arc::clone(&log);
+      s.post(move || {
+        log.write().unwrap().push(0);
+      });
+    }
+    cancelled.unsubscribe();
+
+    thread::sleep(time::Duration::from_millis(400));
+    assert_eq!(*log.read().unwrap(), vec![0, 2]);
+  }
+
+  #[test]
+  fn abort() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let s1 = schedulers::timer_scheduler()();
+    let s2 = schedulers::timer_scheduler()();
+
+    (0..100).for_each(|n| {
+      let log = Arc::clone(&log);
+      s1.schedule_after(
+        time::Duration::from_millis(100),
+        move || {
+          log.write().unwrap().push(n);
+        },
+      );
+    });
+    {
+      let log = Arc::clone(&log);
+      s2.schedule_after(
+        time::Duration::from_millis(100),
+        move || {
+          log.write().unwrap().push(1000);
+        },
+      );
+    }
+    assert_eq!(s1.queue.pending_timers(), 100);
+    s1.abort();
+    assert_eq!(s1.queue.pending_timers(), 0);
+
+    thread::sleep(time::Duration::from_millis(300));
+    assert_eq!(*log.read().unwrap(), vec![1000]);
+    assert_eq!(s2.queue.pending_timers(), 0);
+  }
+
+  #[test]
+  fn many_timeouts() {
+    let timed_out = Arc::new(RwLock::new(0));
+    let sbj = subjects::Subject::<i32>::new();
+
+    (0..1000).for_each(|_| {
+      let timed_out = Arc::clone(&timed_out);
+      sbj
+        .observable()
+        .timeout(
+          time::Duration::from_millis(100),
+          schedulers::timer_scheduler(),
+        )
+        .subscribe(
+          junk_next!(),
+          move |_| *timed_out.write().unwrap() += 1,
+          junk_complete!(),
+        );
+    });
+    sbj.next(1);
+
+    thread::sleep(time::Duration::from_millis(500));
+    assert_eq!(*timed_out.read().unwrap(), 1000);
+  }
+
+  #[test]
+  fn interval() {
+    observables::interval(
+      time::Duration::from_millis(100),
+      schedulers::timer_scheduler(),
+    )
+    .take(5)
+    .subscribe(
+      print_next_fmt!("{}"),
+      print_error!(),
+      print_complete!(),
+    );
+    thread::sleep(time::Duration::from_millis(700));
+  }
+}
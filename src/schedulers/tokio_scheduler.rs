@@ -0,0 +1,320 @@
+use super::scheduler::{IScheduler, ITimedScheduler};
+use crate::internals::function_wrapper::FunctionWrapper;
+use crate::prelude::*;
+use std::{
+  collections::{HashMap, VecDeque},
+  sync::{Arc, RwLock},
+  time::Duration,
+};
+use tokio::{runtime::Handle, task::JoinHandle};
+
+struct TokioSchedulerData {
+  queue: VecDeque<FunctionWrapper<'static, (), ()>>,
+  running: bool,
+  timers: HashMap<u64, JoinHandle<()>>,
+  serial: u64,
+  aborted: bool,
+}
+
+#[derive(Clone)]
+pub struct TokioScheduler {
+  handle: Handle,
+  blocking: bool,
+  data: Arc<RwLock<TokioSchedulerData>>,
+}
+
+impl TokioScheduler {
+  // Work is drained by a task spawned on the runtime workers, yielding
+  // between items.
+  pub fn new(handle: Handle) -> TokioScheduler {
+    TokioScheduler::with_mode(handle, false)
+  }
+
+  // Work is drained on the blocking thread pool (`spawn_blocking`), for
+  // callbacks that block.
+  pub fn new_blocking(handle: Handle) -> TokioScheduler {
+    TokioScheduler::with_mode(handle, true)
+  }
+
+  fn with_mode(handle: Handle, blocking: bool) -> TokioScheduler {
+    TokioScheduler {
+      handle,
+      blocking,
+      data: Arc::new(RwLock::new(TokioSchedulerData {
+        queue: VecDeque::new(),
+        running: false,
+        timers: HashMap::new(),
+        serial: 0,
+        aborted: false,
+      })),
+    }
+  }
+
+  fn next_work(
+    data: &RwLock<TokioSchedulerData>,
+  ) -> Option<FunctionWrapper<'static, (), ()>> {
+    let mut data = data.write().unwrap();
+    let f = if data.aborted {
+      None
+    } else {
+      data.queue.pop_front()
+    };
+    if f.is_none() {
+      data.running = false;
+    }
+    f
+  }
+
+  // Only one drain task runs at a time per scheduler, so posted functions
+  // keep their order.
+  fn drain(&self) {
+    let data = Arc::clone(&self.data);
+    if self.blocking {
+      self.handle.spawn_blocking(move || {
+        while let Some(f) = TokioScheduler::next_work(&data) {
+          f.call(());
+        }
+      });
+    } else {
+      self.handle.spawn(async move {
+        while let Some(f) = TokioScheduler::next_work(&data) {
+          f.call(());
+          tokio::task::yield_now().await;
+        }
+      });
+    }
+  }
+
+  fn spawn_timer<F>(&self, f: F) -> Subscription<'static>
+  where
+    F: FnOnce(u64) -> JoinHandle<()>,
+  {
+    let serial = {
+      let mut data = self.data.write().unwrap();
+      if data.aborted {
+        return Subscription::new(|| {}, || false);
+      }
+      let serial = data.serial;
+      data.serial += 1;
+      // the lock is held until the handle is registered, so a timer firing
+      // immediately still finds its own entry.
+      let join = f(serial);
+      data.timers.insert(serial, join);
+      serial
+    };
+
+    let data_unsub = Arc::clone(&self.data);
+    let data_issub = Arc::clone(&self.data);
+    Subscription::new(
+      move || {
+        if let Some(join) = data_unsub.write().unwrap().timers.remove(&serial) {
+          join.abort();
+        }
+      },
+      move || data_issub.read().unwrap().timers.contains_key(&serial),
+    )
+  }
+}
+
+impl IScheduler<'static> for TokioScheduler {
+  fn post<F>(&self, f: F)
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    let start = {
+      let mut data = self.data.write().unwrap();
+      if data.aborted {
+        return;
+      }
+      data.queue.push_back(FunctionWrapper::new(move |_| f()));
+      let start = !data.running;
+      data.running = true;
+      start
+    };
+    if start {
+      self.drain();
+    }
+  }
+
+  fn abort(&self) {
+    let mut data = self.data.write().unwrap();
+    data.aborted = true;
+    data.queue.clear();
+    data.timers.drain().for_each(|(_, join)| join.abort());
+  }
+}
+
+impl ITimedScheduler<'static> for TokioScheduler {
+  fn schedule_after<F>(&self, dur: Duration, f: F) -> Subscription<'static>
+  where
+    F: Fn() + Clone + Send + Sync + 'static,
+  {
+    let scheduler = self.clone();
+    self.spawn_timer(move |serial| {
+      self.handle.spawn(async move {
+        tokio::time::sleep(dur).await;
+        scheduler.data.write().unwrap().timers.remove(&serial);
+        scheduler.post(f);
+      })
+    })
+  }
+
+  fn schedule_periodic<F>(
+    &self,
+    period: Duration,
+    f: F,
+  ) -> Subscription<'static>
+  where
+    F: Fn() + Clone + Send + Sync + 'static,
+  {
+    let scheduler = self.clone();
+    self.spawn_timer(move |_| {
+      self.handle.spawn(async move {
+        let mut interval = tokio::time::interval_at(
+          tokio::time::Instant::now() + period,
+          period,
+        );
+        loop {
+          interval.tick().await;
+          scheduler.post(f.clone());
+        }
+      })
+    })
+  }
+}
+
+pub fn tokio_scheduler(
+  handle: Handle,
+) -> impl Fn() -> TokioScheduler + Clone + Send + Sync {
+  move || TokioScheduler::new(handle.clone())
+}
+
+pub fn tokio_blocking_scheduler(
+  handle: Handle,
+) -> impl Fn() -> TokioScheduler + Clone + Send + Sync {
+  move || TokioScheduler::new_blocking(handle.clone())
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use scheduler::{IScheduler, ITimedScheduler};
+  use std::{
+    sync::{Arc, RwLock},
+    time,
+  };
+  use tokio::runtime::Handle;
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn ordering() {
+    let scheduler_ctor = schedulers::tokio_scheduler(Handle::current());
+    let items = Arc::new(RwLock::new(Vec::new()));
+    let items_complete = Arc::clone(&items);
+
+    let result = {
+      let items = Arc::clone(&items);
+      observables::from_iter(0..1000)
+        .observe_on(scheduler_ctor)
+        .map(move |x| {
+          items.write().unwrap().push(x);
+          x
+        })
+        .last()
+        .to_vec()
+        .await
+    };
+    assert!(result.is_ok());
+    assert_eq!(
+      *items_complete.read().unwrap(),
+      (0..1000).collect::<Vec<_>>()
+    );
+  }
+
+  #[tokio::test]
+  async fn blocking() {
+    let scheduler_ctor =
+      schedulers::tokio_blocking_scheduler(Handle::current());
+    let result = observables::from_iter(0..5)
+      .subscribe_on(scheduler_ctor.clone())
+      .map(|x| {
+        std::thread::sleep(time::Duration::from_millis(10));
+        x * 2
+      })
+      .observe_on(scheduler_ctor)
+      .to_vec()
+      .await;
+    assert_eq!(result.unwrap(), vec![0, 2, 4, 6, 8]);
+  }
+
+  #[tokio::test]
+  async fn timer() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let s = schedulers::tokio_scheduler(Handle::current())();
+
+    {
+      let log = Arc::clone(&log);
+      s.schedule_after(
+        time::Duration::from_millis(200),
+        move || {
+          log.write().unwrap().push(2);
+        },
+      );
+    }
+    let cancelled = {
+      let log = Arc::clone(&log);
+      s.schedule_after(
+        time::Duration::from_millis(100),
+        move || {
+          log.write().unwrap().push(1);
+        },
+      )
+    };
+    {
+      let log = Arc::clone(&log);
+      s.post(move || {
+        log.write().unwrap().push(0);
+      });
+    }
+    assert!(cancelled.is_subscribed());
+    cancelled.unsubscribe();
+    assert!(!cancelled.is_subscribed());
+
+    tokio::time::sleep(time::Duration::from_millis(400)).await;
+    assert_eq!(*log.read().unwrap(), vec![0, 2]);
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+  async fn interval() {
+    let result = observables::interval(
+      time::Duration::from_millis(50),
+      schedulers::tokio_scheduler(Handle::current()),
+    )
+    .take(5)
+    .to_vec()
+    .await;
+    assert_eq!(result.unwrap(), vec![0, 1, 2, 3, 4]);
+  }
+
+  #[tokio::test]
+  async fn abort() {
+    let log = Arc::new(RwLock::new(Vec::new()));
+    let s = schedulers::tokio_scheduler(Handle::current())();
+
+    {
+      let log = Arc::clone(&log);
+      s.schedule_periodic(
+        time::Duration::from_millis(50),
+        move || {
+          log.write().unwrap().push(0);
+        },
+      );
+    }
+    tokio::time::sleep(time::Duration::from_millis(180)).await;
+    s.abort();
+    let n = log.read().unwrap().len();
+    assert!(n > 0);
+
+    tokio::time::sleep(time::Duration::from_millis(200)).await;
+    assert_eq!(log.read().unwrap().len(), n);
+  }
+}
@@ -1,11 +1,13 @@
 pub mod async_subject;
 pub mod behavior_subject;
 pub mod replay_subject;
+pub mod serialized_subject;
 pub mod subject;
 
 pub mod subjects {
   pub use crate::subjects::async_subject::*;
   pub use crate::subjects::behavior_subject::*;
   pub use crate::subjects::replay_subject::*;
+  pub use crate::subjects::serialized_subject::*;
   pub use crate::subjects::subject::*;
 }
@@ -0,0 +1,127 @@
+use crate::internals::serializer::*;
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct SerializedSubject<'a, Item>
+where
+  Item: Clone + Send + Sync,
+{
+  subject: subjects::Subject<'a, Item>,
+  serializer: Serializer<'a, Item>,
+}
+
+impl<'a, Item> SerializedSubject<'a, Item>
+where
+  Item: Clone + Send + Sync + 'a,
+{
+  pub fn new() -> SerializedSubject<'a, Item> {
+    let subject = subjects::Subject::new();
+    let serializer = {
+      let subject = subject.clone();
+      Serializer::new(move |m| match m {
+        Material::Next(x) => subject.next(x),
+        Material::Error(e) => subject.error(e),
+        Material::Complete => subject.complete(),
+      })
+    };
+    SerializedSubject { subject, serializer }
+  }
+
+  pub fn next(&self, item: Item) {
+    self.serializer.push(Material::Next(item));
+  }
+  pub fn error(&self, err: RxError) {
+    self.serializer.push(Material::Error(err));
+  }
+  pub fn complete(&self) {
+    self.serializer.push(Material::Complete);
+  }
+
+  pub fn observable(&self) -> Observable<'a, Item> {
+    self.subject.observable()
+  }
+}
+
+impl<'a, Item> Default for SerializedSubject<'a, Item>
+where
+  Item: Clone + Send + Sync + 'a,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod test {
+  use crate::prelude::*;
+  use std::{
+    sync::{
+      atomic::{AtomicBool, Ordering},
+      Arc, RwLock,
+    },
+    thread, time,
+  };
+
+  #[test]
+  fn thread() {
+    let sbj = subjects::SerializedSubject::new();
+    let busy = Arc::new(AtomicBool::new(false));
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      sbj.observable().subscribe(
+        move |x| {
+          assert!(!busy.swap(true, Ordering::SeqCst));
+          thread::sleep(time::Duration::from_millis(1));
+          log.write().unwrap().push(x);
+          busy.store(false, Ordering::SeqCst);
+        },
+        print_error!(),
+        print_complete!(),
+      );
+    }
+
+    let threads = (0..4)
+      .map(|n| {
+        let sbj = sbj.clone();
+        thread::spawn(move || {
+          (0..10).for_each(|x| sbj.next(n * 100 + x));
+        })
+      })
+      .collect::<Vec<_>>();
+    threads.into_iter().for_each(|th| {
+      th.join().ok();
+    });
+    sbj.complete();
+    sbj.next(-1);
+
+    assert_eq!(log.read().unwrap().len(), 40);
+  }
+
+  #[test]
+  fn reentrant() {
+    let sbj = subjects::SerializedSubject::new();
+    let log = Arc::new(RwLock::new(Vec::new()));
+
+    {
+      let log = Arc::clone(&log);
+      let sbj_inner = sbj.clone();
+      sbj.observable().subscribe(
+        move |x| {
+          log.write().unwrap().push(x);
+          // delivered after this call returns.
+          if x == 0 {
+            sbj_inner.next(1);
+            log.write().unwrap().push(100);
+          }
+        },
+        print_error!(),
+        print_complete!(),
+      );
+    }
+
+    sbj.next(0);
+    assert_eq!(*log.read().unwrap(), vec![0, 100, 1]);
+  }
+}
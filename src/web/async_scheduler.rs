@@ -1,12 +1,21 @@
-use crate::prelude::{schedulers::IScheduler, web::*};
-use std::time;
+use crate::prelude::{
+  schedulers::{IScheduler, ITimedScheduler},
+  web::*,
+  Subscription,
+};
+use std::{
+  sync::{Arc, RwLock},
+  time,
+};
 
 #[derive(Clone)]
-pub struct AsyncScheduler {}
+pub struct AsyncScheduler {
+  aborted: Arc<RwLock<bool>>,
+}
 
 impl AsyncScheduler {
   pub fn new() -> AsyncScheduler {
-    AsyncScheduler {}
+    AsyncScheduler { aborted: Arc::new(RwLock::new(false)) }
   }
 }
 
@@ -15,9 +24,87 @@ impl IScheduler<'static> for AsyncScheduler {
   where
     F: Fn() + Send + Sync + 'static,
   {
-    set_timeout(f, time::Duration::from_millis(0));
+    let aborted = Arc::clone(&self.aborted);
+    set_timeout(
+      move || {
+        if !*aborted.read().unwrap() {
+          f();
+        }
+      },
+      time::Duration::from_millis(0),
+    );
+  }
+  fn abort(&self) {
+    *self.aborted.write().unwrap() = true;
+  }
+}
+
+impl ITimedScheduler<'static> for AsyncScheduler {
+  fn schedule_after<F>(
+    &self,
+    dur: time::Duration,
+    f: F,
+  ) -> Subscription<'static>
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    let aborted = Arc::clone(&self.aborted);
+    let pending = Arc::new(RwLock::new(true));
+    let pending_timeout = Arc::clone(&pending);
+    let handle = set_timeout(
+      move || {
+        *pending_timeout.write().unwrap() = false;
+        if !*aborted.read().unwrap() {
+          f();
+        }
+      },
+      dur,
+    );
+    let pending_unsub = Arc::clone(&pending);
+    Subscription::new(
+      move || {
+        *pending_unsub.write().unwrap() = false;
+        clear_timeout(handle);
+      },
+      move || *pending.read().unwrap(),
+    )
+  }
+  fn schedule_periodic<F>(
+    &self,
+    period: time::Duration,
+    f: F,
+  ) -> Subscription<'static>
+  where
+    F: Fn() + Send + Sync + 'static,
+  {
+    let aborted = Arc::clone(&self.aborted);
+    let active = Arc::new(RwLock::new(true));
+    let active_interval = Arc::clone(&active);
+    let handle = Arc::new(RwLock::new(None::<i32>));
+    let handle_interval = Arc::clone(&handle);
+    *handle.write().unwrap() = Some(set_interval(
+      move || {
+        if *aborted.read().unwrap() {
+          if let Some(handle) = *handle_interval.read().unwrap() {
+            clear_interval(handle);
+          }
+        } else if *active_interval.read().unwrap() {
+          f();
+        }
+      },
+      period,
+    ));
+    let active_unsub = Arc::clone(&active);
+    Subscription::new(
+      move || {
+        *active_unsub.write().unwrap() = false;
+        if let Some(handle) = *handle.read().unwrap() {
+          clear_interval(handle);
+        }
+      },
+      move || *active.read().unwrap(),
+    )
   }
-  fn abort(&self) {}
 }
 
 pub fn async_scheduler<'a>() -> fn() -> AsyncScheduler {
@@ -2,7 +2,7 @@ use std::{cell::RefCell, rc::Rc, time};
 use wasm_bindgen::{prelude::Closure, JsCast};
 use web_sys::window;
 
-pub fn set_timeout<F>(f: F, dur: time::Duration)
+fn keep_alive_closure<F>(f: F) -> Rc<RefCell<Option<Closure<dyn Fn()>>>>
 where
   F: Fn() + 'static,
 {
@@ -15,7 +15,34 @@ where
     }
   });
   *c.borrow_mut() = Some(closure);
+  c
+}
+
+pub fn set_timeout<F>(f: F, dur: time::Duration) -> i32
+where
+  F: Fn() + 'static,
+{
+  let c = keep_alive_closure(f);
+  let binding = c.borrow();
+  let cc = binding.as_ref().unwrap().as_ref().unchecked_ref();
+  window()
+    .unwrap()
+    .set_timeout_with_callback_and_timeout_and_arguments_0(
+      cc,
+      dur.as_millis() as i32,
+    )
+    .expect("what??")
+}
+
+pub fn clear_timeout(handle: i32) {
+  window().unwrap().clear_timeout_with_handle(handle);
+}
 
+pub fn set_interval<F>(f: F, dur: time::Duration) -> i32
+where
+  F: Fn() + 'static,
+{
+  let c = keep_alive_closure(f);
   let binding = c.borrow();
   let cc = binding.as_ref().unwrap().as_ref().unchecked_ref();
   window()
@@ -24,5 +51,9 @@ where
       cc,
       dur.as_millis() as i32,
     )
-    .expect("what??");
+    .expect("what??")
+}
+
+pub fn clear_interval(handle: i32) {
+  window().unwrap().clear_interval_with_handle(handle);
 }